@@ -1,9 +1,9 @@
 use crate::actions::{
-    ActionSequence, InputSource, KeyAction, KeyActions, MouseActions, PointerAction,
-    MOUSE_BUTTON_LEFT, MOUSE_BUTTON_RIGHT,
+    ActionSequence, InputSource, KeyAction, KeyActions, MouseActions, PointerAction, WheelAction,
+    WheelActions, MOUSE_BUTTON_LEFT, MOUSE_BUTTON_RIGHT,
 };
 use crate::session::handle::SessionHandle;
-use crate::{error::WebDriverResult, WebElement};
+use crate::{error::WebDriverResult, Key, WebElement};
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -27,6 +27,23 @@ pub struct ActionChain {
     handle: Arc<SessionHandle>,
     key_actions: Option<KeyActions>,
     mouse_actions: Option<MouseActions>,
+    wheel_actions: Vec<WheelStep>,
+}
+
+/// A single tick of the wheel input source.
+///
+/// Scrolling to an element requires the element position, which is only resolved
+/// once the action chain is performed.
+#[derive(Debug)]
+enum WheelStep {
+    Pause(Duration),
+    Scroll {
+        x: i64,
+        y: i64,
+        delta_x: i64,
+        delta_y: i64,
+    },
+    ScrollToElement(WebElement),
 }
 
 impl ActionChain {
@@ -39,6 +56,7 @@ impl ActionChain {
             handle,
             key_actions: Some(KeyActions::new("key".to_string())),
             mouse_actions: Some(MouseActions::new("mouse".to_string())),
+            wheel_actions: Vec::new(),
         }
     }
 
@@ -53,6 +71,7 @@ impl ActionChain {
             value: key,
         }));
         self.add_mouse_pause();
+        self.add_wheel_pause();
     }
 
     fn add_key_up(&mut self, key: char) {
@@ -60,6 +79,7 @@ impl ActionChain {
             value: key,
         }));
         self.add_mouse_pause();
+        self.add_wheel_pause();
     }
 
     /// Add a pause for the mouse sequence. Usually required after adding a key event,
@@ -74,6 +94,7 @@ impl ActionChain {
             button,
         }));
         self.add_key_pause();
+        self.add_wheel_pause();
     }
 
     fn add_mouse_up(&mut self, button: u64) {
@@ -81,6 +102,7 @@ impl ActionChain {
             button,
         }));
         self.add_key_pause();
+        self.add_wheel_pause();
     }
 
     fn add_move_to_element(&mut self, element: &WebElement, x_offset: i64, y_offset: i64) {
@@ -92,6 +114,7 @@ impl ActionChain {
                 y: y_offset,
             }));
         self.add_key_pause();
+        self.add_wheel_pause();
     }
 
     fn add_move_to(&mut self, x: i64, y: i64) {
@@ -101,6 +124,7 @@ impl ActionChain {
             y,
         }));
         self.add_key_pause();
+        self.add_wheel_pause();
     }
 
     fn add_move_by(&mut self, x: i64, y: i64) {
@@ -110,6 +134,66 @@ impl ActionChain {
             y,
         }));
         self.add_key_pause();
+        self.add_wheel_pause();
+    }
+
+    /// Add a pause for the wheel sequence, to keep it in sync with the key and mouse sequences.
+    fn add_wheel_pause(&mut self) {
+        self.wheel_actions.push(WheelStep::Pause(Duration::from_millis(0)));
+    }
+
+    fn add_wheel_step(&mut self, step: WheelStep) {
+        self.wheel_actions.push(step);
+        self.add_key_pause();
+        self.add_mouse_pause();
+    }
+
+    /// Resolve the wheel sequence, or return None if it contains no scroll actions.
+    async fn resolve_wheel_actions(&self) -> WebDriverResult<Option<WheelActions>> {
+        if self.wheel_actions.iter().all(|x| matches!(x, WheelStep::Pause(_))) {
+            return Ok(None);
+        }
+
+        let mut wheel_actions = WheelActions::new("wheel".to_string());
+        for step in &self.wheel_actions {
+            let action = match step {
+                WheelStep::Pause(duration) => WheelAction::Pause {
+                    duration: *duration,
+                },
+                WheelStep::Scroll {
+                    x,
+                    y,
+                    delta_x,
+                    delta_y,
+                } => WheelAction::Scroll {
+                    duration: None,
+                    x: *x,
+                    y: *y,
+                    delta_x: *delta_x,
+                    delta_y: *delta_y,
+                },
+                WheelStep::ScrollToElement(element) => {
+                    let ret = self
+                        .handle
+                        .execute(
+                            "const r = arguments[0].getBoundingClientRect(); \
+                             return [Math.round(r.left), Math.round(r.top)];",
+                            vec![element.to_json()?],
+                        )
+                        .await?;
+                    let (delta_x, delta_y): (i64, i64) = ret.convert()?;
+                    WheelAction::Scroll {
+                        duration: None,
+                        x: 0,
+                        y: 0,
+                        delta_x,
+                        delta_y,
+                    }
+                }
+            };
+            wheel_actions.push(action);
+        }
+        Ok(Some(wheel_actions))
     }
 
     /// Reset all actions, reverting all input devices back to default states.
@@ -141,25 +225,36 @@ impl ActionChain {
     /// # }
     /// ```
     pub async fn reset_actions(&self) -> WebDriverResult<()> {
-        self.handle.client.release_actions().await?;
-        Ok(())
+        self.handle.release_actions().await
     }
 
     /// Perform the action sequence. No actions are actually performed until
     /// this method is called.
     pub async fn perform(self) -> WebDriverResult<()> {
+        let wheel_actions = self.resolve_wheel_actions().await?;
+        let mut sequences = vec![
+            ActionSequence::from(self.key_actions.unwrap()),
+            ActionSequence::from(self.mouse_actions.unwrap()),
+        ];
+        if let Some(wheel_actions) = wheel_actions {
+            sequences.push(ActionSequence::from(wheel_actions));
+        }
         self.handle
-            .client
-            .perform_actions(vec![
-                ActionSequence::from(self.key_actions.unwrap()),
-                ActionSequence::from(self.mouse_actions.unwrap()),
-            ])
+            .logged(
+                "perform_actions",
+                "POST actions",
+                self.handle.client.perform_actions(sequences),
+            )
             .await?;
         Ok(())
     }
 
     /// Add a pause in the action sequence.
     ///
+    /// The pause is applied to all input sources, so that subsequent actions are only
+    /// dispatched once the duration has elapsed. The duration is sent to the
+    /// WebDriver in whole milliseconds.
+    ///
     /// # Example:
     /// ```no_run
     /// use std::time::Duration;
@@ -186,6 +281,7 @@ impl ActionChain {
     pub fn pause(mut self, duration: Duration) -> Self {
         self.key_actions = Some(self.key_actions.take().unwrap().pause(duration));
         self.mouse_actions = Some(self.mouse_actions.take().unwrap().pause(duration));
+        self.wheel_actions.push(WheelStep::Pause(duration));
         self
     }
 
@@ -414,53 +510,45 @@ impl ActionChain {
     /// Drag the mouse cursor from the center of the source element to the
     /// center of the target element.
     ///
-    /// *This method is not working correctly due to a selenium bug*
+    /// This performs the drag using pointer actions, which works for elements
+    /// that track mouse events directly. Browsers do not fire HTML5 drag and drop
+    /// events (`dragstart`, `drop`, etc.) for pointer actions,
+    /// see [https://github.com/SeleniumHQ/selenium/issues/8003](https://github.com/SeleniumHQ/selenium/issues/8003).
     ///
-    /// It appears selenium has a bug in the drag and drop feature
-    /// causing it to start the drag but not perform the drop.
-    /// See [https://github.com/SeleniumHQ/selenium/issues/8003](https://github.com/SeleniumHQ/selenium/issues/8003)
-    ///
-    /// This method has been confirmed to produce identical JSON output
-    /// compared to the python selenium library (which also fails due to
-    /// the same bug).
+    /// For HTML5 drag and drop, use `WebElement::js_drag_to()` instead.
     pub fn drag_and_drop_element(self, source: &WebElement, target: &WebElement) -> Self {
         self.click_and_hold_element(source).release_on_element(target)
     }
 
-    /// Drag the mouse cursor by the specified X and Y offsets.
-    ///
-    /// *This method is not working correctly due to a selenium bug*
+    /// Drag the mouse cursor by the specified X and Y offsets, and release
+    /// the left mouse button.
     ///
-    /// It appears selenium has a bug in the drag and drop feature
-    /// causing it to start the drag but not perform the drop.
-    /// See [https://github.com/SeleniumHQ/selenium/issues/8003](https://github.com/SeleniumHQ/selenium/issues/8003)
+    /// This performs the drag using pointer actions, which works for elements
+    /// that track mouse events directly. Browsers do not fire HTML5 drag and drop
+    /// events (`dragstart`, `drop`, etc.) for pointer actions,
+    /// see [https://github.com/SeleniumHQ/selenium/issues/8003](https://github.com/SeleniumHQ/selenium/issues/8003).
     ///
-    /// This method has been confirmed to produce identical JSON output
-    /// compared to the python selenium library (which also fails due to
-    /// the same bug).
+    /// For HTML5 drag and drop, use `WebElement::js_drag_to()` instead.
     pub fn drag_and_drop_by_offset(self, x_offset: i64, y_offset: i64) -> Self {
-        self.click_and_hold().move_by_offset(x_offset, y_offset)
+        self.click_and_hold().move_by_offset(x_offset, y_offset).release()
     }
 
     /// Drag the mouse cursor by the specified X and Y offsets, starting
-    /// from the center of the specified element.
-    ///
-    /// *This method is not working correctly due to a selenium bug*
+    /// from the center of the specified element, and release the left mouse button.
     ///
-    /// It appears selenium has a bug in the drag and drop feature
-    /// causing it to start the drag but not perform the drop.
-    /// See [https://github.com/SeleniumHQ/selenium/issues/8003](https://github.com/SeleniumHQ/selenium/issues/8003)
+    /// This performs the drag using pointer actions, which works for elements
+    /// that track mouse events directly. Browsers do not fire HTML5 drag and drop
+    /// events (`dragstart`, `drop`, etc.) for pointer actions,
+    /// see [https://github.com/SeleniumHQ/selenium/issues/8003](https://github.com/SeleniumHQ/selenium/issues/8003).
     ///
-    /// This method has been confirmed to produce identical JSON output
-    /// compared to the python selenium library (which also fails due to
-    /// the same bug).
+    /// For HTML5 drag and drop, use `WebElement::js_drag_to()` instead.
     pub fn drag_and_drop_element_by_offset(
         self,
         element: &WebElement,
         x_offset: i64,
         y_offset: i64,
     ) -> Self {
-        self.click_and_hold_element(element).move_by_offset(x_offset, y_offset)
+        self.click_and_hold_element(element).move_by_offset(x_offset, y_offset).release()
     }
 
     /// Press the specified key down.
@@ -518,6 +606,9 @@ impl ActionChain {
 
     /// Release the specified key. This usually follows a `key_down()` action.
     ///
+    /// Releasing a key that is not currently pressed is a no-op, as specified by
+    /// the W3C WebDriver spec.
+    ///
     /// # Example:
     /// ```no_run
     /// # use thirtyfour::prelude::*;
@@ -612,6 +703,8 @@ impl ActionChain {
 
     /// Move the mouse cursor by the specified X and Y offsets.
     ///
+    /// The offsets are relative to the current position of the mouse cursor.
+    ///
     /// # Example:
     /// ```no_run
     /// # use thirtyfour::prelude::*;
@@ -675,6 +768,9 @@ impl ActionChain {
     /// Move the mouse cursor to the specified offsets relative to the specified
     /// element's center position.
     ///
+    /// As per the W3C WebDriver spec, the offsets are from the center of the element's
+    /// in-view bounding box, not its top-left corner. Negative offsets move up and to the left.
+    ///
     /// # Example:
     ///
     /// ```no_run
@@ -802,6 +898,65 @@ impl ActionChain {
         self
     }
 
+    /// Send the specified keystrokes to the active element, holding modifier keys.
+    ///
+    /// Unlike [`ActionChain::send_keys()`], modifier keys (`Key::Shift`, `Key::Control`,
+    /// `Key::Alt` and `Key::Meta`) stay pressed until the same modifier is sent again,
+    /// `Key::Null` is sent, or the end of the text is reached. This matches the behaviour
+    /// of `WebElement::send_keys()`, so `Key::Control + "a"` will select all.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// use thirtyfour::Key;
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// let elem = driver.find(By::Name("input1")).await?;
+    /// driver
+    ///     .action_chain()
+    ///     .click_element(&elem)
+    ///     .send_keys_with_modifiers(Key::Control + "a")
+    ///     .send_keys("selenium")
+    ///     .perform()
+    ///     .await?;
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub fn send_keys_with_modifiers(mut self, text: impl AsRef<str>) -> Self {
+        let modifiers = [Key::Shift, Key::Control, Key::Alt, Key::Meta].map(char::from);
+        let mut held: Vec<char> = Vec::new();
+        for c in text.as_ref().chars() {
+            if modifiers.contains(&c) {
+                match held.iter().position(|x| *x == c) {
+                    Some(index) => {
+                        held.remove(index);
+                        self = self.key_up(c);
+                    }
+                    None => {
+                        held.push(c);
+                        self = self.key_down(c);
+                    }
+                }
+            } else if c == char::from(Key::Null) {
+                for modifier in held.drain(..).rev() {
+                    self = self.key_up(modifier);
+                }
+            } else {
+                self = self.key_down(c).key_up(c);
+            }
+        }
+        for modifier in held.into_iter().rev() {
+            self = self.key_up(modifier);
+        }
+        self
+    }
+
     /// Click on the specified element and send the specified keystrokes.
     ///
     /// # Example:
@@ -829,4 +984,90 @@ impl ActionChain {
     pub fn send_keys_to_element(self, element: &WebElement, text: impl AsRef<str>) -> Self {
         self.click_element(element).send_keys(text)
     }
+
+    /// Scroll the page by the specified number of pixels, using the mouse wheel.
+    ///
+    /// The scroll originates from the top-left corner of the viewport.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// // Scroll down by 500 pixels.
+    /// driver.action_chain().scroll_by(0, 500).perform().await?;
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub fn scroll_by(self, delta_x: i64, delta_y: i64) -> Self {
+        self.scroll_from_point(0, 0, delta_x, delta_y)
+    }
+
+    /// Scroll by the specified number of pixels, using the mouse wheel, with the scroll
+    /// originating from the specified point in the viewport.
+    ///
+    /// This is useful for scrolling within a scrollable element rather than the page.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// // Scroll down by 200 pixels, with the mouse wheel positioned at (100, 300).
+    /// driver.action_chain().scroll_from_point(100, 300, 0, 200).perform().await?;
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub fn scroll_from_point(mut self, x: i64, y: i64, delta_x: i64, delta_y: i64) -> Self {
+        self.add_wheel_step(WheelStep::Scroll {
+            x,
+            y,
+            delta_x,
+            delta_y,
+        });
+        self
+    }
+
+    /// Scroll the page until the specified element is at the top-left of the viewport,
+    /// if possible, using the mouse wheel.
+    ///
+    /// **NOTE:** The position of the element is measured once when `perform()` is called,
+    ///           before any of the actions in the chain are performed. Any earlier action
+    ///           that scrolls the page or changes the layout makes that measurement stale,
+    ///           so `scroll_to()` must be the first scroll in the chain, and should come
+    ///           before any action that changes the layout. Otherwise, perform the earlier
+    ///           actions in a separate chain first.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// let elem = driver.find(By::Id("footer")).await?;
+    /// driver.action_chain().scroll_to(&elem).perform().await?;
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub fn scroll_to(mut self, element: &WebElement) -> Self {
+        self.add_wheel_step(WheelStep::ScrollToElement(element.clone()));
+        self
+    }
 }
@@ -76,7 +76,7 @@ impl SessionHandle {
     /// # }
     /// ```
     pub async fn get_alert_text(&self) -> WebDriverResult<String> {
-        Ok(self.client.get_alert_text().await?)
+        Ok(self.logged("get_alert_text", "GET alert/text", self.client.get_alert_text()).await?)
     }
 
     /// Dismiss the active alert.
@@ -97,7 +97,7 @@ impl SessionHandle {
     /// # }
     /// ```
     pub async fn dismiss_alert(&self) -> WebDriverResult<()> {
-        self.client.dismiss_alert().await?;
+        self.logged("dismiss_alert", "POST alert/dismiss", self.client.dismiss_alert()).await?;
         Ok(())
     }
 
@@ -119,7 +119,7 @@ impl SessionHandle {
     /// # }
     /// ```
     pub async fn accept_alert(&self) -> WebDriverResult<()> {
-        self.client.accept_alert().await?;
+        self.logged("accept_alert", "POST alert/accept", self.client.accept_alert()).await?;
         Ok(())
     }
 
@@ -161,7 +161,12 @@ impl SessionHandle {
     /// # }
     /// ```
     pub async fn send_alert_text(&self, keys: impl AsRef<str>) -> WebDriverResult<()> {
-        self.client.send_alert_text(keys.as_ref()).await?;
+        self.logged(
+            "send_alert_text",
+            "POST alert/text",
+            self.client.send_alert_text(keys.as_ref()),
+        )
+        .await?;
         Ok(())
     }
 }
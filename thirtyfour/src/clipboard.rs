@@ -0,0 +1,82 @@
+use crate::error::{WebDriverError, WebDriverResult};
+use crate::session::handle::SessionHandle;
+use serde_json::Value;
+use std::sync::Arc;
+
+/// Run the specified clipboard operation and pass the result, or the reason it was
+/// rejected, to the async script callback.
+const CLIPBOARD_SCRIPT: &str = r#"
+    const [operation, text, done] = arguments;
+    Promise.resolve()
+        .then(() => operation === "read"
+            ? navigator.clipboard.readText()
+            : navigator.clipboard.writeText(text))
+        .then(
+            (text) => done({ text: text ?? null }),
+            (e) => done({ error: `${e.name}: ${e.message}` })
+        );
+"#;
+
+impl SessionHandle {
+    /// Run the specified clipboard operation, returning a clear error if it was rejected.
+    async fn clipboard(
+        self: &Arc<SessionHandle>,
+        operation: &str,
+        text: &str,
+    ) -> WebDriverResult<Value> {
+        let ret = self
+            .execute_async(CLIPBOARD_SCRIPT, vec![Value::from(operation), Value::from(text)])
+            .await?;
+        let mut ret = ret.json().clone();
+        match ret["error"].as_str() {
+            Some(error) => Err(WebDriverError::CustomError(format!(
+                "the clipboard API rejected the {operation} ({error}). Grant the \
+                clipboard-read and clipboard-write permissions first, using \
+                WebDriver::grant_permissions(), and make sure the page has focus"
+            ))),
+            None => Ok(ret["text"].take()),
+        }
+    }
+
+    /// Read the text on the clipboard, using the `navigator.clipboard` API of the current page.
+    ///
+    /// The browser must allow the page to access the clipboard, so grant the
+    /// `clipboard-read` permission first. Returns an error if access was denied.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// driver.goto("https://www.rust-lang.org/").await?;
+    /// driver.grant_permissions(&["clipboard-read", "clipboard-write"]).await?;
+    /// driver.find(By::Id("copy-button")).await?.click().await?;
+    /// assert_eq!(driver.read_clipboard().await?, "cargo install thirtyfour");
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn read_clipboard(self: &Arc<SessionHandle>) -> WebDriverResult<String> {
+        let text = self.clipboard("read", "").await?;
+        Ok(serde_json::from_value(text)?)
+    }
+
+    /// Write the specified text to the clipboard, using the `navigator.clipboard` API of
+    /// the current page.
+    ///
+    /// The browser must allow the page to access the clipboard, so grant the
+    /// `clipboard-write` permission first. Returns an error if access was denied.
+    /// See [`SessionHandle::read_clipboard()`] for an example.
+    pub async fn write_clipboard(
+        self: &Arc<SessionHandle>,
+        text: impl AsRef<str>,
+    ) -> WebDriverResult<()> {
+        self.clipboard("write", text.as_ref()).await?;
+        Ok(())
+    }
+}
@@ -1,10 +1,37 @@
 use serde::Serialize;
 use serde_json::{json, Value};
+use std::collections::HashMap;
 
 use crate::common::capabilities::chromium::ChromiumLikeCapabilities;
+use crate::error::WebDriverResult;
 use crate::{BrowserCapabilitiesHelper, Capabilities, CapabilitiesHelper};
 
 /// Capabilities for Chrome.
+///
+/// All Chrome-specific options are nested under the `goog:chromeOptions` key.
+///
+/// # Example:
+/// ```
+/// # use thirtyfour::prelude::*;
+/// use serde_json::json;
+///
+/// # fn main() -> WebDriverResult<()> {
+/// let mut caps = DesiredCapabilities::chrome();
+/// caps.set_headless()?;
+/// caps.add_arg("--window-size=1920,1080")?;
+/// caps.set_binary("/usr/bin/google-chrome")?;
+///
+/// let caps: Capabilities = caps.into();
+/// assert_eq!(
+///     caps["goog:chromeOptions"],
+///     json!({
+///         "args": ["--headless", "--window-size=1920,1080"],
+///         "binary": "/usr/bin/google-chrome"
+///     })
+/// );
+/// # Ok(())
+/// # }
+/// ```
 #[derive(Debug, Clone, Serialize)]
 #[serde(transparent)]
 pub struct ChromeCapabilities {
@@ -26,6 +53,46 @@ impl ChromeCapabilities {
             capabilities,
         }
     }
+
+    /// Set the log level for each log type (e.g. `"browser"` or `"performance"`).
+    ///
+    /// The log levels are `OFF`, `SEVERE`, `WARNING`, `INFO`, `CONFIG`, `FINE`, `FINER`,
+    /// `FINEST` and `ALL`. The logs can be read using `WebDriver::get_log()`.
+    pub fn set_logging_prefs(&mut self, prefs: HashMap<String, String>) -> WebDriverResult<()> {
+        self.set_base_capability("goog:loggingPrefs", prefs)
+    }
+
+    /// Enable the performance log, which includes the Chrome DevTools Protocol network
+    /// events for the session. Any other logging preferences are kept.
+    ///
+    /// The log can be read using `WebDriver::get_log(LogType::Performance)`.
+    ///
+    /// # Example:
+    /// ```
+    /// # use thirtyfour::prelude::*;
+    /// use serde_json::json;
+    /// use std::collections::HashMap;
+    ///
+    /// # fn main() -> WebDriverResult<()> {
+    /// let mut caps = DesiredCapabilities::chrome();
+    /// caps.set_logging_prefs(HashMap::from([("browser".to_string(), "SEVERE".to_string())]))?;
+    /// caps.enable_performance_logging()?;
+    ///
+    /// let caps: Capabilities = caps.into();
+    /// assert_eq!(caps["goog:loggingPrefs"], json!({ "browser": "SEVERE", "performance": "ALL" }));
+    /// assert_eq!(caps["goog:chromeOptions"]["perfLoggingPrefs"], json!({ "enableNetwork": true }));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn enable_performance_logging(&mut self) -> WebDriverResult<()> {
+        match self._get_mut("goog:loggingPrefs") {
+            Some(Value::Object(prefs)) => {
+                prefs.insert("performance".to_string(), json!("ALL"));
+            }
+            _ => self.set_base_capability("goog:loggingPrefs", json!({ "performance": "ALL" }))?,
+        }
+        self.add_experimental_option("perfLoggingPrefs", json!({ "enableNetwork": true }))
+    }
 }
 
 impl CapabilitiesHelper for ChromeCapabilities {
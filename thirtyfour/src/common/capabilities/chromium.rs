@@ -80,9 +80,14 @@ pub trait ChromiumLikeCapabilities: BrowserCapabilitiesHelper {
     ///
     /// ## Example
     ///
-    /// ```ignore
+    /// ```
+    /// # use thirtyfour::prelude::*;
+    /// # fn main() -> WebDriverResult<()> {
     /// let mut caps = DesiredCapabilities::chrome();
-    /// caps.add_chrome_arg("--disable-local-storage")?;
+    /// caps.add_arg("--disable-local-storage")?;
+    /// assert!(caps.has_arg("--disable-local-storage"));
+    /// # Ok(())
+    /// # }
     /// ```
     ///
     /// The full list of switches can be found here:
@@ -112,6 +117,14 @@ pub trait ChromiumLikeCapabilities: BrowserCapabilitiesHelper {
         self.args().contains(&arg.to_string())
     }
 
+    /// Override the user agent string, replacing any previous `--user-agent` argument.
+    fn set_user_agent(&mut self, user_agent: &str) -> WebDriverResult<()> {
+        let mut args = self.args();
+        args.retain(|x| !x.starts_with("--user-agent="));
+        args.push(format!("--user-agent={user_agent}"));
+        self.insert_browser_option("args", to_value(args)?)
+    }
+
     /// Add the specified experimental option.
     ///
     /// ## Example
@@ -170,6 +183,83 @@ pub trait ChromiumLikeCapabilities: BrowserCapabilitiesHelper {
         self.remove_encoded_extension(&b64_contents)
     }
 
+    /// Save downloaded files to the specified directory, without prompting.
+    ///
+    /// This sets the `download.default_directory` and `download.prompt_for_download`
+    /// preferences, keeping any other preferences already set.
+    ///
+    /// ## Example
+    /// ```
+    /// # use thirtyfour::prelude::*;
+    /// use serde_json::json;
+    /// use std::path::Path;
+    ///
+    /// # fn main() -> WebDriverResult<()> {
+    /// let mut caps = DesiredCapabilities::chrome();
+    /// caps.set_download_directory(Path::new("/tmp/downloads"))?;
+    ///
+    /// let caps: Capabilities = caps.into();
+    /// assert_eq!(
+    ///     caps["goog:chromeOptions"]["prefs"],
+    ///     json!({
+    ///         "download.default_directory": "/tmp/downloads",
+    ///         "download.prompt_for_download": false
+    ///     })
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn set_download_directory(&mut self, path: &Path) -> WebDriverResult<()> {
+        let mut prefs: serde_json::Map<String, Value> =
+            self.browser_option("prefs").unwrap_or_default();
+        prefs.insert("download.default_directory".to_string(), json!(path.to_string_lossy()));
+        prefs.insert("download.prompt_for_download".to_string(), json!(false));
+        self.insert_browser_option("prefs", prefs)
+    }
+
+    /// Set the mobile device to emulate.
+    ///
+    /// ## Example
+    /// ```
+    /// # use thirtyfour::prelude::*;
+    /// use serde_json::json;
+    /// use thirtyfour::{DeviceMetrics, MobileEmulation};
+    ///
+    /// # fn main() -> WebDriverResult<()> {
+    /// let mut caps = DesiredCapabilities::chrome();
+    /// caps.set_mobile_emulation(MobileEmulation::device("iPhone X"))?;
+    ///
+    /// // Or emulate a custom device.
+    /// caps.set_mobile_emulation(MobileEmulation::Custom {
+    ///     metrics: DeviceMetrics {
+    ///         width: 360,
+    ///         height: 640,
+    ///         pixel_ratio: 3.0,
+    ///         touch: true,
+    ///     },
+    ///     user_agent: Some("Mozilla/5.0 (Linux; Android 10)".to_string()),
+    /// })?;
+    ///
+    /// let caps: Capabilities = caps.into();
+    /// assert_eq!(
+    ///     caps["goog:chromeOptions"]["mobileEmulation"],
+    ///     json!({
+    ///         "deviceMetrics": { "width": 360, "height": 640, "pixelRatio": 3.0, "touch": true },
+    ///         "userAgent": "Mozilla/5.0 (Linux; Android 10)"
+    ///     })
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn set_mobile_emulation(&mut self, emulation: MobileEmulation) -> WebDriverResult<()> {
+        self.insert_browser_option("mobileEmulation", emulation)
+    }
+
+    /// Unset the mobile device emulation.
+    fn unset_mobile_emulation(&mut self) {
+        self.remove_browser_option("mobileEmulation");
+    }
+
     /// Get the list of exclude switches.
     fn exclude_switches(&self) -> Vec<String> {
         self.browser_option("excludeSwitches").unwrap_or_default()
@@ -207,6 +297,53 @@ pub trait ChromiumLikeCapabilities: BrowserCapabilitiesHelper {
     }
 }
 
+/// The mobile device to emulate in a chromium-based browser.
+///
+/// A device is either a named device known to the browser, or a custom set of
+/// device metrics. Only one of these can be specified.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum MobileEmulation {
+    /// A device known to the browser, such as `"iPhone X"` or `"Pixel 7"`.
+    Device {
+        /// The device name, as shown in the DevTools device list.
+        #[serde(rename = "deviceName")]
+        name: String,
+    },
+    /// A custom device.
+    Custom {
+        /// The device screen metrics.
+        #[serde(rename = "deviceMetrics")]
+        metrics: DeviceMetrics,
+        /// The user agent to use. If not set, the browser's default user agent is used.
+        #[serde(rename = "userAgent", skip_serializing_if = "Option::is_none")]
+        user_agent: Option<String>,
+    },
+}
+
+impl MobileEmulation {
+    /// Emulate the specified named device.
+    pub fn device(name: impl Into<String>) -> Self {
+        MobileEmulation::Device {
+            name: name.into(),
+        }
+    }
+}
+
+/// The screen metrics of a custom device for mobile emulation.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeviceMetrics {
+    /// The screen width in pixels.
+    pub width: u32,
+    /// The screen height in pixels.
+    pub height: u32,
+    /// The device pixel ratio.
+    pub pixel_ratio: f64,
+    /// Whether to emulate touch events.
+    pub touch: bool,
+}
+
 /// Capabilities for Chromium.
 #[derive(Debug, Clone, Serialize)]
 #[serde(transparent)]
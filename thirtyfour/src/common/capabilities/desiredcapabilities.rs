@@ -3,13 +3,14 @@ use serde::{Deserialize, Serialize};
 use serde_json::{from_value, json, to_value, Value};
 
 use crate::common::capabilities::chrome::ChromeCapabilities;
+use crate::common::capabilities::chromium::ChromiumLikeCapabilities;
 use crate::common::capabilities::edge::EdgeCapabilities;
 use crate::common::capabilities::firefox::FirefoxCapabilities;
 use crate::common::capabilities::ie::InternetExplorerCapabilities;
 use crate::common::capabilities::opera::OperaCapabilities;
 use crate::common::capabilities::safari::SafariCapabilities;
-use crate::error::WebDriverResult;
-use crate::{Capabilities, ChromiumCapabilities};
+use crate::error::{WebDriverError, WebDriverErrorDetails, WebDriverResult};
+use crate::{Capabilities, ChromiumCapabilities, TimeoutConfiguration};
 
 /// Provides static methods for constructing browser-specific capabilities.
 ///
@@ -57,6 +58,41 @@ impl DesiredCapabilities {
     pub fn safari() -> SafariCapabilities {
         SafariCapabilities::new()
     }
+
+    /// Create capabilities from the specified JSON value, which must be an object.
+    ///
+    /// This is useful for loading capability profiles from configuration files.
+    ///
+    /// ## Example
+    /// ```
+    /// # use thirtyfour::prelude::*;
+    /// use serde_json::json;
+    ///
+    /// # fn main() -> WebDriverResult<()> {
+    /// let caps = DesiredCapabilities::from_json(json!({
+    ///     "browserName": "chrome",
+    ///     "goog:chromeOptions": { "args": ["--headless"] }
+    /// }))?;
+    /// assert_eq!(caps.browser_name().as_deref(), Some("chrome"));
+    /// assert_eq!(caps.as_json()["goog:chromeOptions"]["args"], json!(["--headless"]));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn from_json(value: Value) -> WebDriverResult<Capabilities> {
+        match value {
+            Value::Object(capabilities) => Ok(capabilities),
+            v => Err(WebDriverError::InvalidArgument(WebDriverErrorDetails::new(format!(
+                "capabilities must be a JSON object, got: {v}"
+            )))),
+        }
+    }
+
+    /// Create capabilities from the specified JSON string, which must contain an object.
+    ///
+    /// See [`DesiredCapabilities::from_json()`] for more details.
+    pub fn from_json_str(json: &str) -> WebDriverResult<Capabilities> {
+        Self::from_json(serde_json::from_str(json)?)
+    }
 }
 
 /// Provides common features for all Capabilities structs.
@@ -79,6 +115,160 @@ pub trait CapabilitiesHelper {
         Ok(())
     }
 
+    /// Get the capabilities as a JSON value.
+    fn as_json(&self) -> Value
+    where
+        Self: Serialize,
+    {
+        to_value(self).unwrap_or_default()
+    }
+
+    /// Deep merge the specified capabilities into these capabilities.
+    ///
+    /// Objects are merged recursively, and any other values in `other` replace the
+    /// existing values. Arrays are either replaced or concatenated, depending on `arrays`.
+    /// This is useful for layering environment-specific or per-test options onto a shared
+    /// set of base capabilities.
+    ///
+    /// ## Example
+    /// ```
+    /// # use thirtyfour::prelude::*;
+    /// use serde_json::json;
+    /// use thirtyfour::ArrayMerge;
+    ///
+    /// # fn main() -> WebDriverResult<()> {
+    /// let mut base = DesiredCapabilities::chrome();
+    /// base.add_arg("--no-sandbox")?;
+    /// base.set_binary("/usr/bin/chromium")?;
+    ///
+    /// let mut overrides = DesiredCapabilities::chrome();
+    /// overrides.set_headless()?;
+    /// base.merge(overrides, ArrayMerge::Concatenate);
+    ///
+    /// let caps: Capabilities = base.into();
+    /// assert_eq!(
+    ///     caps["goog:chromeOptions"],
+    ///     json!({ "args": ["--no-sandbox", "--headless"], "binary": "/usr/bin/chromium" })
+    /// );
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn merge(&mut self, other: impl Into<Capabilities>, arrays: ArrayMerge)
+    where
+        Self: Sized,
+    {
+        let other: Capabilities = other.into();
+        for (key, value) in other {
+            match self._get_mut(&key) {
+                Some(existing) => merge_json(existing, value, arrays),
+                None => self.insert_base_capability(key, value),
+            }
+        }
+    }
+
+    /// Get the browser name, if one was set.
+    fn browser_name(&self) -> Option<String> {
+        self._get("browserName").and_then(|x| x.as_str()).map(String::from)
+    }
+
+    /// Enable headless mode, using the correct argument for the current browser.
+    ///
+    /// Chrome, Chromium and Edge will use `--headless=new`, and Firefox will use `-headless`.
+    /// Returns an error if the browser name is missing or not one of these browsers.
+    ///
+    /// ## Example
+    /// ```
+    /// # use thirtyfour::prelude::*;
+    /// use serde_json::json;
+    ///
+    /// # fn main() -> WebDriverResult<()> {
+    /// let mut caps: Capabilities = DesiredCapabilities::firefox().into();
+    /// caps.enable_headless()?;
+    /// assert_eq!(caps["moz:firefoxOptions"]["args"], json!(["-headless"]));
+    ///
+    /// let mut caps = Capabilities::new();
+    /// assert!(caps.enable_headless().is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn enable_headless(&mut self) -> WebDriverResult<()> {
+        let browser_name = self.browser_name();
+        let (key, arg) = match browser_name.as_deref() {
+            Some("chrome") | Some("chromium") => ("goog:chromeOptions", "--headless=new"),
+            Some("MicrosoftEdge") => ("ms:edgeOptions", "--headless=new"),
+            Some("firefox") => ("moz:firefoxOptions", "-headless"),
+            _ => {
+                return Err(WebDriverError::InvalidArgument(WebDriverErrorDetails::new(format!(
+                    "headless mode is not supported for browser: {}",
+                    browser_name.as_deref().unwrap_or("<unknown>")
+                ))));
+            }
+        };
+
+        match self._get_mut(key) {
+            Some(Value::Object(options)) => match options.get_mut("args") {
+                Some(Value::Array(args)) => {
+                    if !args.iter().any(|x| x == arg) {
+                        args.push(json!(arg));
+                    }
+                }
+                _ => {
+                    options.insert("args".to_string(), json!([arg]));
+                }
+            },
+            _ => self.insert_base_capability(key.to_string(), json!({ "args": [arg] })),
+        }
+        Ok(())
+    }
+
+    /// Override the user agent string, using the correct option for the current browser.
+    ///
+    /// Chrome, Chromium and Edge will use the `--user-agent` argument, and Firefox will use
+    /// the `general.useragent.override` preference. Returns an error if the browser name is
+    /// missing or not one of these browsers.
+    ///
+    /// ## Example
+    /// ```
+    /// # use thirtyfour::prelude::*;
+    /// use serde_json::json;
+    ///
+    /// # fn main() -> WebDriverResult<()> {
+    /// let mut caps: Capabilities = DesiredCapabilities::chrome().into();
+    /// caps.override_user_agent("my-agent/1.0")?;
+    /// assert_eq!(caps["goog:chromeOptions"]["args"], json!(["--user-agent=my-agent/1.0"]));
+    ///
+    /// let mut caps: Capabilities = DesiredCapabilities::firefox().into();
+    /// caps.override_user_agent("my-agent/1.0")?;
+    /// assert_eq!(
+    ///     caps["moz:firefoxOptions"]["prefs"]["general.useragent.override"],
+    ///     "my-agent/1.0"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn override_user_agent(&mut self, user_agent: &str) -> WebDriverResult<()> {
+        let browser_name = self.browser_name();
+        match browser_name.as_deref() {
+            Some("chrome") | Some("chromium") => {
+                update_browser_options(self, |caps: &mut ChromeCapabilities| {
+                    caps.set_user_agent(user_agent)
+                })
+            }
+            Some("MicrosoftEdge") => update_browser_options(self, |caps: &mut EdgeCapabilities| {
+                caps.set_user_agent(user_agent)
+            }),
+            Some("firefox") => update_browser_options(self, |caps: &mut FirefoxCapabilities| {
+                let mut preferences = caps.preferences();
+                preferences.set_user_agent(user_agent.to_string())?;
+                caps.set_preferences(preferences)
+            }),
+            _ => Err(WebDriverError::InvalidArgument(WebDriverErrorDetails::new(format!(
+                "overriding the user agent is not supported for browser: {}",
+                browser_name.as_deref().unwrap_or("<unknown>")
+            )))),
+        }
+    }
+
     /// Set the desired browser version.
     fn set_version(&mut self, version: &str) -> WebDriverResult<()> {
         self.set_base_capability("version", version)
@@ -126,6 +316,25 @@ pub trait CapabilitiesHelper {
     }
 
     /// Set whether the session should accept insecure SSL certificates by default.
+    ///
+    /// This is useful for testing against servers with self-signed certificates. It is
+    /// set as the standard W3C `acceptInsecureCerts` capability at the root of the
+    /// capabilities, so it is supported by all W3C-compatible drivers. Like all
+    /// capabilities, it only applies when the session is created.
+    ///
+    /// ## Example
+    /// ```
+    /// use thirtyfour::prelude::*;
+    ///
+    /// # fn main() -> WebDriverResult<()> {
+    /// let mut caps = DesiredCapabilities::chrome();
+    /// caps.accept_insecure_certs(true)?;
+    ///
+    /// let caps: Capabilities = caps.into();
+    /// assert_eq!(caps["acceptInsecureCerts"], serde_json::json!(true));
+    /// #     Ok(())
+    /// # }
+    /// ```
     fn accept_insecure_certs(&mut self, enabled: bool) -> WebDriverResult<()> {
         self.set_base_capability("acceptInsecureCerts", enabled)
     }
@@ -142,6 +351,18 @@ pub trait CapabilitiesHelper {
     }
 
     /// Set the proxy to use.
+    ///
+    /// ## Example
+    /// ```
+    /// # use thirtyfour::prelude::*;
+    /// use thirtyfour::Proxy;
+    ///
+    /// # fn main() -> WebDriverResult<()> {
+    /// let mut caps = DesiredCapabilities::chrome();
+    /// caps.set_proxy(Proxy::manual(Some("localhost:8080"), Some("localhost:8080"), None))?;
+    /// # Ok(())
+    /// # }
+    /// ```
     fn set_proxy(&mut self, proxy: Proxy) -> WebDriverResult<()> {
         self.set_base_capability("proxy", proxy)
     }
@@ -151,6 +372,76 @@ pub trait CapabilitiesHelper {
         self.set_base_capability("unexpectedAlertBehaviour", behaviour)
     }
 
+    /// Set the initial timeouts for the session.
+    ///
+    /// These are applied when the session is created, so no separate request is
+    /// needed to set them afterwards. They can still be changed later using
+    /// `WebDriver::update_timeouts()`.
+    ///
+    /// ## Example
+    /// ```
+    /// use std::time::Duration;
+    /// use thirtyfour::prelude::*;
+    ///
+    /// # fn main() -> WebDriverResult<()> {
+    /// let mut caps = DesiredCapabilities::chrome();
+    /// let timeouts = TimeoutConfiguration::builder()
+    ///     .script(Duration::from_secs(30))
+    ///     .page_load(Duration::from_secs(120))
+    ///     .implicit(Duration::from_secs(0))
+    ///     .build();
+    /// caps.set_timeouts(timeouts)?;
+    ///
+    /// let caps: Capabilities = caps.into();
+    /// assert_eq!(
+    ///     caps["timeouts"],
+    ///     serde_json::json!({ "script": 30000, "pageLoad": 120000, "implicit": 0 })
+    /// );
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn set_timeouts(&mut self, timeouts: TimeoutConfiguration) -> WebDriverResult<()> {
+        self.set_base_capability("timeouts", timeouts)
+    }
+
+    /// Set whether file inputs must be interactable (e.g. visible) before keys can be
+    /// sent to them.
+    ///
+    /// By default, keys can be sent to hidden file inputs.
+    fn set_strict_file_interactability(&mut self, enabled: bool) -> WebDriverResult<()> {
+        self.set_base_capability("strictFileInteractability", enabled)
+    }
+
+    /// Get the behaviour to be followed when a user prompt is not handled, if set.
+    ///
+    /// Returns an error if the capability is set to a value that is not a valid
+    /// `AlertBehaviour`.
+    fn unhandled_prompt_behaviour(&self) -> WebDriverResult<Option<AlertBehaviour>> {
+        Ok(self._get("unhandledPromptBehavior").map(|x| from_value(x.clone())).transpose()?)
+    }
+
+    /// Set the behaviour to be followed when a user prompt (such as `alert()` or `confirm()`)
+    /// is open and a command is sent that does not handle it.
+    ///
+    /// ## Example
+    /// ```
+    /// use thirtyfour::prelude::*;
+    /// use thirtyfour::AlertBehaviour;
+    ///
+    /// # fn main() -> WebDriverResult<()> {
+    /// let mut caps = DesiredCapabilities::chrome();
+    /// caps.set_unhandled_prompt_behaviour(AlertBehaviour::DismissAndNotify)?;
+    /// assert_eq!(caps.unhandled_prompt_behaviour()?, Some(AlertBehaviour::DismissAndNotify));
+    ///
+    /// let caps: Capabilities = caps.into();
+    /// assert_eq!(caps["unhandledPromptBehavior"], "dismiss and notify");
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn set_unhandled_prompt_behaviour(&mut self, behaviour: AlertBehaviour) -> WebDriverResult<()> {
+        self.set_base_capability("unhandledPromptBehavior", behaviour)
+    }
+
     /// Set whether elements are scrolled into the viewport for interation to align with the top
     /// or the bottom of the viewport. The default is to align with the top.
     fn set_element_scroll_behaviour(&mut self, behaviour: ScrollBehaviour) -> WebDriverResult<()> {
@@ -174,11 +465,78 @@ pub trait CapabilitiesHelper {
     }
 
     /// Set the page load strategy to use.
+    ///
+    /// ## Example
+    /// ```
+    /// use thirtyfour::prelude::*;
+    /// use thirtyfour::PageLoadStrategy;
+    ///
+    /// # fn main() -> WebDriverResult<()> {
+    /// let mut caps = DesiredCapabilities::chrome();
+    /// // Don't wait for images and stylesheets to load.
+    /// caps.set_page_load_strategy(PageLoadStrategy::Eager)?;
+    /// assert_eq!(caps.page_load_strategy()?, PageLoadStrategy::Eager);
+    ///
+    /// let caps: Capabilities = caps.into();
+    /// assert_eq!(caps["pageLoadStrategy"], "eager");
+    /// #     Ok(())
+    /// # }
+    /// ```
     fn set_page_load_strategy(&mut self, strategy: PageLoadStrategy) -> WebDriverResult<()> {
         self.set_base_capability("pageLoadStrategy", strategy)
     }
 }
 
+/// How arrays are combined by `CapabilitiesHelper::merge()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArrayMerge {
+    /// Replace the existing array with the new one.
+    Replace,
+    /// Append the elements of the new array to the existing array.
+    Concatenate,
+}
+
+/// Update the browser-specific options of `caps` using the helpers for the browser
+/// capabilities type `C`.
+fn update_browser_options<T, C>(
+    caps: &mut T,
+    f: impl FnOnce(&mut C) -> WebDriverResult<()>,
+) -> WebDriverResult<()>
+where
+    T: CapabilitiesHelper + ?Sized,
+    C: BrowserCapabilitiesHelper + Default,
+{
+    let mut browser_caps = C::default();
+    if let Some(options) = caps._get(C::KEY) {
+        browser_caps.insert_base_capability(C::KEY.to_string(), options.clone());
+    }
+    f(&mut browser_caps)?;
+    if let Some(options) = browser_caps._get(C::KEY) {
+        caps.insert_base_capability(C::KEY.to_string(), options.clone());
+    }
+    Ok(())
+}
+
+/// Deep merge `other` into `value`.
+fn merge_json(value: &mut Value, other: Value, arrays: ArrayMerge) {
+    match (value, other) {
+        (Value::Object(existing), Value::Object(other)) => {
+            for (key, other_value) in other {
+                match existing.get_mut(&key) {
+                    Some(existing_value) => merge_json(existing_value, other_value, arrays),
+                    None => {
+                        existing.insert(key, other_value);
+                    }
+                }
+            }
+        }
+        (Value::Array(existing), Value::Array(other)) if arrays == ArrayMerge::Concatenate => {
+            existing.extend(other);
+        }
+        (value, other) => *value = other,
+    }
+}
+
 /// Helper trait for adding browser-specific capabilities.
 ///
 /// For example, chrome stores capabilities under `goog:chromeOptions` and firefox
@@ -281,8 +639,75 @@ pub enum Proxy {
     System,
 }
 
+impl Proxy {
+    /// Manual proxy configuration using the specified HTTP, SSL and SOCKS proxies.
+    ///
+    /// Each proxy should be specified as `host:port`. If a SOCKS proxy is specified,
+    /// SOCKS version 5 is used.
+    ///
+    /// ## Example
+    /// ```
+    /// use serde_json::json;
+    /// use thirtyfour::Proxy;
+    ///
+    /// let proxy = Proxy::manual(Some("localhost:8080"), Some("localhost:8080"), None);
+    /// assert_eq!(
+    ///     serde_json::to_value(proxy).unwrap(),
+    ///     json!({
+    ///         "proxyType": "manual",
+    ///         "httpProxy": "localhost:8080",
+    ///         "sslProxy": "localhost:8080"
+    ///     })
+    /// );
+    /// ```
+    pub fn manual(http: Option<&str>, ssl: Option<&str>, socks: Option<&str>) -> Self {
+        Proxy::Manual {
+            ftp_proxy: None,
+            http_proxy: http.map(String::from),
+            ssl_proxy: ssl.map(String::from),
+            socks_proxy: socks.map(String::from),
+            socks_version: socks.map(|_| 5),
+            socks_username: None,
+            socks_password: None,
+            no_proxy: None,
+        }
+    }
+
+    /// Proxy configuration using the specified proxy autoconfiguration (PAC) url.
+    ///
+    /// ## Example
+    /// ```
+    /// use serde_json::json;
+    /// use thirtyfour::Proxy;
+    ///
+    /// let proxy = Proxy::pac("http://localhost/proxy.pac");
+    /// assert_eq!(
+    ///     serde_json::to_value(proxy).unwrap(),
+    ///     json!({ "proxyType": "pac", "proxyAutoconfigUrl": "http://localhost/proxy.pac" })
+    /// );
+    /// ```
+    pub fn pac(url: impl Into<String>) -> Self {
+        Proxy::AutoConfig {
+            url: url.into(),
+        }
+    }
+
+    /// Auto-detect the proxy configuration.
+    pub fn auto_detect() -> Self {
+        Proxy::AutoDetect
+    }
+
+    /// Connect directly, without a proxy.
+    pub fn direct() -> Self {
+        Proxy::Direct
+    }
+}
+
 /// The action to take when an alert is encountered.
-#[derive(Debug, Clone, Serialize)]
+///
+/// The "notify" variants handle the alert and also return an `UnexpectedAlertOpen`
+/// error for the command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum AlertBehaviour {
     /// Automatically accept the alert.
@@ -291,6 +716,12 @@ pub enum AlertBehaviour {
     Dismiss,
     /// Ignore the alert.
     Ignore,
+    /// Automatically accept the alert and return an error.
+    #[serde(rename = "accept and notify")]
+    AcceptAndNotify,
+    /// Automatically dismiss the alert and return an error.
+    #[serde(rename = "dismiss and notify")]
+    DismissAndNotify,
 }
 
 /// The automatic scrolling behaviour for this session.
@@ -304,7 +735,9 @@ pub enum ScrollBehaviour {
 }
 
 /// The page load strategy for this session.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// This determines when navigation commands such as `WebDriver::goto()` return.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum PageLoadStrategy {
     /// Wait for full page loading (the default).
@@ -321,3 +754,130 @@ impl Default for PageLoadStrategy {
         PageLoadStrategy::Normal
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::capabilities::chromium::ChromiumLikeCapabilities;
+
+    #[test]
+    fn test_merge_nested_options() -> WebDriverResult<()> {
+        let mut base = DesiredCapabilities::chrome();
+        base.add_arg("--no-sandbox")?;
+        base.add_experimental_option("prefs", json!({ "a": 1, "nested": { "b": 2 } }))?;
+
+        let mut other = DesiredCapabilities::chrome();
+        other.add_arg("--headless")?;
+        other.add_experimental_option("prefs", json!({ "nested": { "c": 3 } }))?;
+        other.accept_insecure_certs(true)?;
+
+        let mut replaced = base.clone();
+        replaced.merge(other.clone(), ArrayMerge::Replace);
+        let caps: Capabilities = replaced.into();
+        assert_eq!(caps["browserName"], "chrome");
+        assert_eq!(caps["acceptInsecureCerts"], true);
+        assert_eq!(
+            caps["goog:chromeOptions"],
+            json!({
+                "args": ["--headless"],
+                "prefs": { "a": 1, "nested": { "b": 2, "c": 3 } }
+            })
+        );
+
+        base.merge(other, ArrayMerge::Concatenate);
+        let caps: Capabilities = base.into();
+        assert_eq!(caps["goog:chromeOptions"]["args"], json!(["--no-sandbox", "--headless"]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_overwrites_scalars() {
+        let mut caps = Capabilities::new();
+        caps.insert("pageLoadStrategy".to_string(), json!("normal"));
+        caps.insert("timeouts".to_string(), json!({ "script": 1000 }));
+
+        let mut other = Capabilities::new();
+        other.insert("pageLoadStrategy".to_string(), json!("eager"));
+        other.insert("timeouts".to_string(), json!(null));
+        caps.merge(other, ArrayMerge::Replace);
+        assert_eq!(caps["pageLoadStrategy"], "eager");
+        assert_eq!(caps["timeouts"], Value::Null);
+    }
+
+    #[test]
+    fn test_user_agent() -> WebDriverResult<()> {
+        let mut caps = DesiredCapabilities::chrome();
+        caps.add_arg("--no-sandbox")?;
+        caps.set_user_agent("first")?;
+        caps.set_user_agent("second")?;
+        assert_eq!(caps.args(), vec!["--no-sandbox", "--user-agent=second"]);
+
+        let mut caps: Capabilities = DesiredCapabilities::edge().into();
+        caps.override_user_agent("first")?;
+        caps.override_user_agent("second")?;
+        assert_eq!(caps["ms:edgeOptions"]["args"], json!(["--user-agent=second"]));
+
+        let mut caps = DesiredCapabilities::firefox();
+        caps.set_preference("pdfjs.disabled", true)?;
+        let mut caps: Capabilities = caps.into();
+        caps.override_user_agent("first")?;
+        caps.override_user_agent("second")?;
+        assert_eq!(
+            caps["moz:firefoxOptions"]["prefs"],
+            json!({ "pdfjs.disabled": true, "general.useragent.override": "second" })
+        );
+
+        assert!(DesiredCapabilities::safari().override_user_agent("agent").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_download_directory() -> WebDriverResult<()> {
+        let mut caps = DesiredCapabilities::chrome();
+        caps.add_experimental_option("prefs", json!({ "intl.accept_languages": "en" }))?;
+        caps.set_download_directory(std::path::Path::new("/tmp/downloads"))?;
+        let caps: Capabilities = caps.into();
+        assert_eq!(caps["goog:chromeOptions"]["prefs"]["intl.accept_languages"], "en");
+        assert_eq!(caps["goog:chromeOptions"]["prefs"]["download.prompt_for_download"], false);
+
+        let mut caps = DesiredCapabilities::firefox();
+        caps.set_download_directory(std::path::Path::new("/tmp/downloads"))?;
+        let caps: Capabilities = caps.into();
+        assert_eq!(
+            caps["moz:firefoxOptions"]["prefs"],
+            json!({
+                "browser.download.dir": "/tmp/downloads",
+                "browser.download.folderList": 2,
+                "browser.download.useDownloadDir": true
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_json() -> WebDriverResult<()> {
+        let caps = DesiredCapabilities::from_json_str(
+            r#"{ "browserName": "firefox", "moz:firefoxOptions": { "args": ["-headless"] } }"#,
+        )?;
+        assert_eq!(caps.browser_name().as_deref(), Some("firefox"));
+        assert_eq!(caps.as_json()["moz:firefoxOptions"], json!({ "args": ["-headless"] }));
+
+        assert!(matches!(
+            DesiredCapabilities::from_json(json!(["chrome"])),
+            Err(WebDriverError::InvalidArgument(_))
+        ));
+        assert!(DesiredCapabilities::from_json_str("{").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_as_json() -> WebDriverResult<()> {
+        let mut caps = DesiredCapabilities::chrome();
+        caps.add_arg("--headless")?;
+        assert_eq!(
+            caps.as_json(),
+            json!({ "browserName": "chrome", "goog:chromeOptions": { "args": ["--headless"] } })
+        );
+        Ok(())
+    }
+}
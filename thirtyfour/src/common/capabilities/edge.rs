@@ -5,6 +5,24 @@ use crate::common::capabilities::chromium::ChromiumLikeCapabilities;
 use crate::{BrowserCapabilitiesHelper, Capabilities, CapabilitiesHelper};
 
 /// Capabilities for Microsoft Edge.
+///
+/// All Edge-specific options are nested under the `ms:edgeOptions` key.
+///
+/// # Example:
+/// ```
+/// # use thirtyfour::prelude::*;
+/// use serde_json::json;
+///
+/// # fn main() -> WebDriverResult<()> {
+/// let mut caps = DesiredCapabilities::edge();
+/// caps.set_headless()?;
+///
+/// let caps: Capabilities = caps.into();
+/// assert_eq!(caps["browserName"], "MicrosoftEdge");
+/// assert_eq!(caps["ms:edgeOptions"], json!({ "args": ["--headless"] }));
+/// # Ok(())
+/// # }
+/// ```
 #[derive(Debug, Clone, Serialize)]
 #[serde(transparent)]
 pub struct EdgeCapabilities {
@@ -1,3 +1,5 @@
+use std::path::Path;
+
 use paste::paste;
 use serde::{Deserialize, Serialize};
 use serde_json::{from_value, json, to_value, Value};
@@ -7,6 +9,37 @@ use crate::CapabilitiesHelper;
 use crate::{BrowserCapabilitiesHelper, Capabilities};
 
 /// Capabilities for Firefox.
+///
+/// All Firefox-specific options are nested under the `moz:firefoxOptions` key.
+///
+/// Firefox extensions cannot be installed via capabilities. Use
+/// [`FirefoxTools::install_addon()`] once the session has started instead.
+///
+/// [`FirefoxTools::install_addon()`]: crate::extensions::addons::firefox::FirefoxTools::install_addon
+///
+/// # Example:
+/// ```
+/// # use thirtyfour::prelude::*;
+/// use serde_json::json;
+///
+/// # fn main() -> WebDriverResult<()> {
+/// let mut caps = DesiredCapabilities::firefox();
+/// caps.set_headless()?;
+/// caps.set_firefox_binary("/usr/bin/firefox")?;
+/// caps.set_preference("pdfjs.disabled", true)?;
+///
+/// let caps: Capabilities = caps.into();
+/// assert_eq!(
+///     caps["moz:firefoxOptions"],
+///     json!({
+///         "args": ["-headless"],
+///         "binary": "/usr/bin/firefox",
+///         "prefs": { "pdfjs.disabled": true }
+///     })
+/// );
+/// # Ok(())
+/// # }
+/// ```
 #[derive(Debug, Clone, Serialize)]
 #[serde(transparent)]
 pub struct FirefoxCapabilities {
@@ -85,11 +118,62 @@ impl FirefoxCapabilities {
         self.insert_browser_option("binary", start_cmd)
     }
 
+    /// Get the current firefox preferences.
+    pub fn preferences(&self) -> FirefoxPreferences {
+        self.browser_option("prefs").unwrap_or_default()
+    }
+
     /// Set the firefox preferences to use.
+    ///
+    /// This replaces any preferences that were previously set.
     pub fn set_preferences(&mut self, preferences: FirefoxPreferences) -> WebDriverResult<()> {
         self.insert_browser_option("prefs", preferences)
     }
 
+    /// Set the specified firefox preference, keeping any other preferences already set.
+    ///
+    /// The value can be a bool, integer or string.
+    ///
+    /// # Example:
+    /// ```
+    /// # use thirtyfour::prelude::*;
+    /// # fn main() -> WebDriverResult<()> {
+    /// let mut caps = DesiredCapabilities::firefox();
+    /// // Download PDF files rather than opening them in the built-in viewer.
+    /// caps.set_preference("pdfjs.disabled", true)?;
+    /// caps.set_preference("browser.download.folderList", 2)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_preference<T>(&mut self, key: &str, value: T) -> WebDriverResult<()>
+    where
+        T: Serialize,
+    {
+        let mut preferences = self.preferences();
+        preferences.set(key, value)?;
+        self.set_preferences(preferences)
+    }
+
+    /// Save downloaded files to the specified directory, without prompting.
+    ///
+    /// This sets the `browser.download.dir`, `browser.download.folderList` and
+    /// `browser.download.useDownloadDir` preferences, keeping any other preferences already set.
+    pub fn set_download_directory(&mut self, path: &Path) -> WebDriverResult<()> {
+        let mut preferences = self.preferences();
+        preferences.set("browser.download.dir", path.to_string_lossy())?;
+        // 2 means the custom directory set above, rather than the default downloads folder.
+        preferences.set("browser.download.folderList", 2)?;
+        preferences.set("browser.download.useDownloadDir", true)?;
+        self.set_preferences(preferences)
+    }
+
+    /// Unset the specified firefox preference if it had been set previously.
+    pub fn unset_preference(&mut self, key: &str) -> WebDriverResult<()> {
+        let mut preferences = self.preferences();
+        preferences.unset(key)?;
+        self.set_preferences(preferences)
+    }
+
     /// Get the firefox profile zip as a base64-encoded string.
     pub fn encoded_profile(&self) -> Option<String> {
         self.browser_option("profile")
@@ -1,6 +1,12 @@
-use crate::Locator;
+use crate::common::print::PrintParameters;
+use crate::error::WebDriverResult;
+use crate::upstream::{ElementRef, WebDriverCompatibleCommand};
+use crate::{Locator, LogType, OptionRect, WebElement};
+use http::Method;
+use serde_json::{json, Value};
 use std::fmt;
 use std::fmt::Debug;
+use url::{ParseError, Url};
 
 /// The webdriver selector to use when querying elements.
 #[derive(Debug, Clone)]
@@ -36,6 +42,23 @@ impl fmt::Display for By {
     }
 }
 
+/// Quote the specified string for use as a CSS attribute value.
+fn css_string(value: &str) -> String {
+    format!(r#""{}""#, value.replace('\\', r"\\").replace('"', r#"\""#))
+}
+
+/// Quote the specified string for use as an XPath string literal.
+fn xpath_string(value: &str) -> String {
+    if !value.contains('"') {
+        format!(r#""{}""#, value)
+    } else if !value.contains('\'') {
+        format!("'{}'", value)
+    } else {
+        let parts: Vec<String> = value.split('"').map(|x| format!(r#""{}""#, x)).collect();
+        format!("concat({})", parts.join(r#", '"', "#))
+    }
+}
+
 #[allow(non_snake_case)]
 impl By {
     /// Select element by id.
@@ -52,6 +75,18 @@ impl By {
         }
     }
 
+    /// Select link element whose text contains the specified text.
+    ///
+    /// This uses an XPath selector, so it cannot be used to search within a shadow root.
+    pub fn PartialLinkText(text: &str) -> Self {
+        Self {
+            selector: BySelector::XPath(format!(
+                ".//a[contains(normalize-space(.), {})]",
+                xpath_string(text)
+            )),
+        }
+    }
+
     /// Select element by CSS.
     pub fn Css(css: &str) -> Self {
         Self {
@@ -87,8 +122,62 @@ impl By {
         }
     }
 
+    /// Select element by id. Same as `By::Id()`.
+    pub fn id(id: &str) -> Self {
+        Self::Id(id)
+    }
+
+    /// Select element by link text. Same as `By::LinkText()`.
+    pub fn link_text(text: &str) -> Self {
+        Self::LinkText(text)
+    }
+
+    /// Select link element whose text contains the specified text.
+    /// Same as `By::PartialLinkText()`.
+    pub fn partial_link_text(text: &str) -> Self {
+        Self::PartialLinkText(text)
+    }
+
+    /// Select element by CSS. Same as `By::Css()`.
+    pub fn css(css: &str) -> Self {
+        Self::Css(css)
+    }
+
+    /// Select element by XPath. Same as `By::XPath()`.
+    pub fn xpath(x: &str) -> Self {
+        Self::XPath(x)
+    }
+
+    /// Select element by name. Same as `By::Name()`.
+    pub fn name(name: &str) -> Self {
+        Self::Name(name)
+    }
+
+    /// Select element by tag. Same as `By::Tag()`.
+    pub fn tag(tag: &str) -> Self {
+        Self::Tag(tag)
+    }
+
+    /// Select element by class. Same as `By::ClassName()`.
+    pub fn class_name(name: &str) -> Self {
+        Self::ClassName(name)
+    }
+
+    /// Get the selector to use when searching from an element.
+    ///
+    /// The id locator is sent as an XPath relative to the document root, which would also
+    /// match elements outside of the element's subtree, so use a CSS selector instead.
+    pub(crate) fn for_element(self) -> Self {
+        match self.selector {
+            BySelector::Id(id) => Self {
+                selector: BySelector::Css(format!("[id={}]", css_string(&id))),
+            },
+            _ => self,
+        }
+    }
+
     /// Get the [`Locator`] for this selector.
-    pub fn locator(&self) -> Locator {
+    pub fn locator(&self) -> Locator<'_> {
         match &self.selector {
             BySelector::Id(id) => Locator::Id(id),
             BySelector::LinkText(text) => Locator::LinkText(text),
@@ -124,3 +213,215 @@ pub fn locator_to_string(locator: Locator<'_>) -> String {
         Locator::XPath(s) => format!("XPath({})", s),
     }
 }
+
+/// The default maximum distance in pixels for `RelativeBy::near()`.
+const DEFAULT_NEAR_DISTANCE: f64 = 50.0;
+
+/// Relative locator, for finding elements by their position relative to other elements.
+///
+/// Elements matching the selector are filtered by their on-screen position relative to
+/// the specified anchor elements. Results are sorted by proximity to the first anchor.
+///
+/// Use with `WebDriver::find_relative()` or `WebDriver::find_all_relative()`.
+///
+/// # Example:
+/// ```no_run
+/// # use thirtyfour::prelude::*;
+/// # use thirtyfour::support::block_on;
+/// use thirtyfour::RelativeBy;
+/// #
+/// # fn main() -> WebDriverResult<()> {
+/// #     block_on(async {
+/// #         let caps = DesiredCapabilities::chrome();
+/// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+/// let label = driver.find(By::Id("username-label")).await?;
+/// let input = driver.find_relative(RelativeBy::with(By::Tag("input")).below(&label)).await?;
+/// #         driver.quit().await?;
+/// #         Ok(())
+/// #     })
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RelativeBy {
+    by: By,
+    filters: Vec<(&'static str, WebElement, f64)>,
+}
+
+impl RelativeBy {
+    /// Find elements matching the specified selector, subject to relative filters.
+    pub fn with(by: impl Into<By>) -> Self {
+        Self {
+            by: by.into(),
+            filters: Vec::new(),
+        }
+    }
+
+    fn filter(mut self, kind: &'static str, anchor: &WebElement, distance: f64) -> Self {
+        self.filters.push((kind, anchor.clone(), distance));
+        self
+    }
+
+    /// Only match elements that are above the specified element.
+    pub fn above(self, anchor: &WebElement) -> Self {
+        self.filter("above", anchor, 0.0)
+    }
+
+    /// Only match elements that are below the specified element.
+    pub fn below(self, anchor: &WebElement) -> Self {
+        self.filter("below", anchor, 0.0)
+    }
+
+    /// Only match elements that are to the left of the specified element.
+    pub fn to_left_of(self, anchor: &WebElement) -> Self {
+        self.filter("left", anchor, 0.0)
+    }
+
+    /// Only match elements that are to the right of the specified element.
+    pub fn to_right_of(self, anchor: &WebElement) -> Self {
+        self.filter("right", anchor, 0.0)
+    }
+
+    /// Only match elements that are within 50 pixels of the specified element.
+    pub fn near(self, anchor: &WebElement) -> Self {
+        self.near_within(anchor, DEFAULT_NEAR_DISTANCE)
+    }
+
+    /// Only match elements that are within `distance` pixels of the specified element.
+    pub fn near_within(self, anchor: &WebElement, distance: f64) -> Self {
+        self.filter("near", anchor, distance)
+    }
+
+    /// Get the JSON argument for the relative locator script.
+    pub(crate) fn to_json(&self) -> WebDriverResult<Value> {
+        let mut filters = Vec::new();
+        for (kind, anchor, distance) in &self.filters {
+            filters
+                .push(json!({ "kind": kind, "anchor": anchor.to_json()?, "distance": distance }));
+        }
+        let mut locator = locator_parameters(&self.by);
+        locator["filters"] = Value::Array(filters);
+        Ok(locator)
+    }
+}
+
+impl fmt::Display for RelativeBy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RelativeBy({})", self.by)?;
+        for (kind, anchor, _) in &self.filters {
+            write!(f, ".{}({})", kind, anchor.element_id())?;
+        }
+        Ok(())
+    }
+}
+
+/// W3C WebDriver commands that are not provided by fantoccini.
+#[derive(Debug)]
+pub(crate) enum Command {
+    /// Print the current page to PDF.
+    Print(PrintParameters),
+    /// Get the computed ARIA role of the specified element.
+    GetComputedRole(ElementRef),
+    /// Get the computed accessible label of the specified element.
+    GetComputedLabel(ElementRef),
+    /// Get the shadow root of the specified element.
+    GetElementShadowRoot(ElementRef),
+    /// Get the specified property of the specified element, as JSON.
+    GetElementProperty(ElementRef, String),
+    /// Find an element within the specified shadow root.
+    FindElementFromShadowRoot(String, By),
+    /// Find all elements within the specified shadow root.
+    FindElementsFromShadowRoot(String, By),
+    /// Upload a base64-encoded zip file to the remote WebDriver (Selenium-specific).
+    UploadFile(String),
+    /// End the session.
+    DeleteSession,
+    /// Take a screenshot of the entire page (Firefox-specific).
+    #[cfg(feature = "image")]
+    FullPageScreenshot,
+    /// Get the entries of the specified log (Selenium-specific).
+    GetLog(LogType),
+    /// Get the entries of the specified log, using the legacy endpoint.
+    GetLegacyLog(LogType),
+    /// Get the available log types (Selenium-specific).
+    GetLogTypes,
+    /// Get the available log types, using the legacy endpoint.
+    GetLegacyLogTypes,
+    /// Get the window rectangle. Unlike fantoccini, this allows negative coordinates.
+    GetWindowRect,
+    /// Set the window rectangle, leaving any omitted fields unchanged.
+    SetWindowRect(OptionRect),
+}
+
+/// Convert the specified selector into the W3C locator parameters.
+pub(crate) fn locator_parameters(by: &By) -> Value {
+    let (using, value) = match &by.selector {
+        BySelector::Id(id) => ("css selector", format!("[id={}]", css_string(id))),
+        BySelector::LinkText(text) => ("link text", text.clone()),
+        BySelector::Css(css) => ("css selector", css.clone()),
+        BySelector::XPath(xpath) => ("xpath", xpath.clone()),
+    };
+    json!({ "using": using, "value": value })
+}
+
+impl WebDriverCompatibleCommand for Command {
+    fn endpoint(&self, base_url: &Url, session_id: Option<&str>) -> Result<Url, ParseError> {
+        let base = { base_url.join(&format!("session/{}/", session_id.as_ref().unwrap()))? };
+        match &self {
+            Command::Print(_) => base.join("print"),
+            Command::GetComputedRole(element_id) => {
+                base.join(&format!("element/{element_id}/computedrole"))
+            }
+            Command::GetComputedLabel(element_id) => {
+                base.join(&format!("element/{element_id}/computedlabel"))
+            }
+            Command::GetElementShadowRoot(element_id) => {
+                base.join(&format!("element/{element_id}/shadow"))
+            }
+            Command::GetElementProperty(element_id, name) => {
+                base.join(&format!("element/{element_id}/property/{name}"))
+            }
+            Command::FindElementFromShadowRoot(shadow_id, _) => {
+                base.join(&format!("shadow/{shadow_id}/element"))
+            }
+            Command::FindElementsFromShadowRoot(shadow_id, _) => {
+                base.join(&format!("shadow/{shadow_id}/elements"))
+            }
+            Command::UploadFile(_) => base.join("se/file"),
+            Command::DeleteSession => {
+                base_url.join(&format!("session/{}", session_id.as_ref().unwrap()))
+            }
+            #[cfg(feature = "image")]
+            Command::FullPageScreenshot => base.join("moz/screenshot/full"),
+            Command::GetLog(_) => base.join("se/log"),
+            Command::GetLegacyLog(_) => base.join("log"),
+            Command::GetLogTypes => base.join("se/log/types"),
+            Command::GetLegacyLogTypes => base.join("log/types"),
+            Command::GetWindowRect | Command::SetWindowRect(_) => base.join("window/rect"),
+        }
+    }
+
+    fn method_and_body(&self, _request_url: &Url) -> (Method, Option<String>) {
+        match &self {
+            Command::Print(params) => (Method::POST, Some(json!(params).to_string())),
+            Command::GetComputedRole(_)
+            | Command::GetComputedLabel(_)
+            | Command::GetElementShadowRoot(_)
+            | Command::GetElementProperty(..)
+            | Command::GetLogTypes
+            | Command::GetLegacyLogTypes
+            | Command::GetWindowRect => (Method::GET, None),
+            #[cfg(feature = "image")]
+            Command::FullPageScreenshot => (Method::GET, None),
+            Command::FindElementFromShadowRoot(_, by)
+            | Command::FindElementsFromShadowRoot(_, by) => {
+                (Method::POST, Some(locator_parameters(by).to_string()))
+            }
+            Command::UploadFile(file) => (Method::POST, Some(json!({ "file": file }).to_string())),
+            Command::DeleteSession => (Method::DELETE, None),
+            Command::GetLog(log_type) | Command::GetLegacyLog(log_type) => {
+                (Method::POST, Some(json!({ "type": log_type }).to_string()))
+            }
+            Command::SetWindowRect(rect) => (Method::POST, Some(json!(rect).to_string())),
+        }
+    }
+}
@@ -1,5 +1,9 @@
 use crate::extensions::query::{ElementPollerWithTimeout, IntoElementPoller};
 use std::sync::Arc;
+use std::time::Duration;
+
+/// The default timeout for each HTTP request to the WebDriver server.
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(120);
 
 /// Configuration options used by a `WebDriver` instance and the related `SessionHandle`.
 ///
@@ -9,6 +13,16 @@ use std::sync::Arc;
 pub struct WebDriverConfig {
     /// The default poller to use when performing element queries or waits.
     pub poller: Arc<dyn IntoElementPoller + Send + Sync>,
+    /// The timeout for each HTTP request to the WebDriver server.
+    ///
+    /// This is separate from the WebDriver timeouts (script, page load, implicit wait)
+    /// and protects against a WebDriver server that stops responding.
+    /// It only applies when creating a new session, e.g. via `WebDriver::new_with_config()`.
+    pub request_timeout: Duration,
+    /// The policy for retrying failed connections to the WebDriver server.
+    ///
+    /// It only applies when creating a new session, e.g. via `WebDriver::new_with_config()`.
+    pub retry_policy: RetryPolicy,
 }
 
 impl Default for WebDriverConfig {
@@ -28,6 +42,8 @@ impl WebDriverConfig {
 #[derive(Debug, Clone)]
 pub struct WebDriverConfigBuilder {
     poller: Option<Arc<dyn IntoElementPoller + Send + Sync>>,
+    request_timeout: Duration,
+    retry_policy: RetryPolicy,
 }
 
 impl Default for WebDriverConfigBuilder {
@@ -41,6 +57,8 @@ impl WebDriverConfigBuilder {
     pub fn new() -> Self {
         Self {
             poller: None,
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+            retry_policy: RetryPolicy::none(),
         }
     }
 
@@ -50,10 +68,81 @@ impl WebDriverConfigBuilder {
         self
     }
 
+    /// Set the timeout for each HTTP request to the WebDriver server.
+    ///
+    /// If the server does not respond within this time, the command will return
+    /// `WebDriverError::Timeout`. The default is 120 seconds.
+    ///
+    /// This should be longer than the page load and script timeouts, otherwise
+    /// slow pages or scripts will fail with a request timeout instead.
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = timeout;
+        self
+    }
+
+    /// Set the policy for retrying failed connections to the WebDriver server.
+    ///
+    /// By default, failed connections are not retried.
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
     /// Build `WebDriverConfig` using builder options.
     pub fn build(self) -> WebDriverConfig {
         WebDriverConfig {
             poller: self.poller.unwrap_or_else(|| Arc::new(ElementPollerWithTimeout::default())),
+            request_timeout: self.request_timeout,
+            retry_policy: self.retry_policy,
         }
     }
 }
+
+/// Policy for retrying failed connections to the WebDriver server.
+///
+/// This is useful for Selenium grids that briefly refuse or drop connections while scaling.
+/// Only establishing the connection is retried, because no request has been sent at that
+/// point. Requests that fail after they were sent are never replayed, so commands such as
+/// `NewSession` will not be executed twice.
+///
+/// HTTP error responses, such as `502 Bad Gateway` or `503 Service Unavailable` from a grid
+/// that is still starting up, are **not** retried, because the connection was established.
+///
+/// # Example:
+/// ```
+/// use std::time::Duration;
+/// use thirtyfour::common::config::{RetryPolicy, WebDriverConfig};
+///
+/// // Try to connect up to 4 times, waiting 0.5s, 1s and then 2s between attempts.
+/// let policy = RetryPolicy::new(4, Duration::from_millis(500));
+/// let config = WebDriverConfig::builder().retry_policy(policy).build();
+/// assert_eq!(config.retry_policy.max_attempts, 4);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts to connect, including the first attempt.
+    pub max_attempts: u32,
+    /// The delay before the first retry. The delay is doubled after each retry.
+    pub backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+impl RetryPolicy {
+    /// Create a new `RetryPolicy` with the specified number of attempts and initial backoff.
+    pub fn new(max_attempts: u32, backoff: Duration) -> Self {
+        Self {
+            max_attempts,
+            backoff,
+        }
+    }
+
+    /// Create a `RetryPolicy` that does not retry failed connections.
+    pub fn none() -> Self {
+        Self::new(1, Duration::ZERO)
+    }
+}
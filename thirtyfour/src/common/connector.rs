@@ -0,0 +1,62 @@
+use crate::common::config::RetryPolicy;
+use futures::future::BoxFuture;
+use hyper::service::Service;
+use hyper::Uri;
+use std::task::{Context, Poll};
+
+/// HTTP connector that retries failed connection attempts according to a `RetryPolicy`.
+///
+/// Only establishing the connection is retried. No request data has been sent at that
+/// point, so this is safe for every command, including `NewSession`.
+#[derive(Debug, Clone)]
+pub(crate) struct RetryConnector<C> {
+    inner: C,
+    policy: RetryPolicy,
+}
+
+impl<C> RetryConnector<C> {
+    /// Wrap the specified connector.
+    pub(crate) fn new(inner: C, policy: RetryPolicy) -> Self {
+        Self {
+            inner,
+            policy,
+        }
+    }
+}
+
+impl<C> Service<Uri> for RetryConnector<C>
+where
+    C: Service<Uri> + Clone + Send + 'static,
+    C::Future: Send,
+    C::Response: Send,
+    C::Error: Send,
+{
+    type Response = C::Response;
+    type Error = C::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, uri: Uri) -> Self::Future {
+        // The inner connector was made ready by poll_ready(), so use it for the first attempt.
+        let first_attempt = self.inner.call(uri.clone());
+        let mut inner = self.inner.clone();
+        let policy = self.policy.clone();
+        Box::pin(async move {
+            let mut result = first_attempt.await;
+            let mut backoff = policy.backoff;
+            for _ in 1..policy.max_attempts {
+                if result.is_ok() {
+                    break;
+                }
+                tokio::time::sleep(backoff).await;
+                backoff *= 2;
+                futures::future::poll_fn(|cx| inner.poll_ready(cx)).await?;
+                result = inner.call(uri.clone()).await;
+            }
+            result
+        })
+    }
+}
@@ -0,0 +1,109 @@
+use std::time::SystemTime;
+
+use cookie::time::OffsetDateTime;
+
+use crate::cookie::SameSite;
+use crate::Cookie;
+
+/// Provides typed access to the expiry time of a `Cookie`, and chained setters for
+/// building a `Cookie`.
+///
+/// The WebDriver protocol represents cookie expiry as whole seconds since the Unix epoch,
+/// so any sub-second precision is discarded when the cookie is sent to the browser.
+///
+/// # Example:
+/// ```
+/// use std::time::{Duration, SystemTime, UNIX_EPOCH};
+/// use thirtyfour::prelude::*;
+///
+/// let expiry = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+/// let cookie = Cookie::new("key", "value").with_expiry(expiry);
+/// assert_eq!(cookie.expiry(), Some(expiry));
+///
+/// // Cookies without an expiry time are session cookies.
+/// assert_eq!(Cookie::new("key", "value").expiry(), None);
+///
+/// // Create a cookie that expires in one hour.
+/// let cookie = Cookie::new("key", "value")
+///     .with_expiry(SystemTime::now() + Duration::from_secs(60 * 60));
+/// ```
+///
+/// The chained setters make it easy to build a cookie to pass to `WebDriver::add_cookie()`.
+/// ```
+/// use thirtyfour::cookie::SameSite;
+/// use thirtyfour::prelude::*;
+///
+/// let token = String::from("abc123");
+/// let cookie = Cookie::new("session", token)
+///     .with_domain(".example.com")
+///     .with_path("/")
+///     .with_secure(true)
+///     .with_http_only(true)
+///     .with_same_site(SameSite::Strict);
+/// assert_eq!(cookie.domain(), Some("example.com"));
+/// assert_eq!(cookie.secure(), Some(true));
+/// assert_eq!(cookie.same_site(), Some(SameSite::Strict));
+/// ```
+pub trait CookieHelper: Sized {
+    /// Get the expiry time of this cookie, or `None` if it is a session cookie.
+    fn expiry(&self) -> Option<SystemTime>;
+
+    /// Set the expiry time of this cookie.
+    fn set_expiry(&mut self, expiry: SystemTime);
+
+    /// Set the expiry time of this cookie and return the cookie.
+    fn with_expiry(mut self, expiry: SystemTime) -> Self {
+        self.set_expiry(expiry);
+        self
+    }
+
+    /// Set the domain of this cookie and return the cookie.
+    fn with_domain(self, domain: impl Into<String>) -> Self;
+
+    /// Set the path of this cookie and return the cookie.
+    fn with_path(self, path: impl Into<String>) -> Self;
+
+    /// Set whether this cookie is only sent over HTTPS and return the cookie.
+    fn with_secure(self, secure: bool) -> Self;
+
+    /// Set whether this cookie is hidden from JavaScript and return the cookie.
+    fn with_http_only(self, http_only: bool) -> Self;
+
+    /// Set the `SameSite` attribute of this cookie and return the cookie.
+    fn with_same_site(self, same_site: SameSite) -> Self;
+}
+
+impl CookieHelper for Cookie<'_> {
+    fn expiry(&self) -> Option<SystemTime> {
+        self.expires_datetime().map(SystemTime::from)
+    }
+
+    fn set_expiry(&mut self, expiry: SystemTime) {
+        self.set_expires(OffsetDateTime::from(expiry));
+    }
+
+    fn with_domain(mut self, domain: impl Into<String>) -> Self {
+        self.set_domain(domain.into());
+        self
+    }
+
+    fn with_path(mut self, path: impl Into<String>) -> Self {
+        self.set_path(path.into());
+        self
+    }
+
+    fn with_secure(mut self, secure: bool) -> Self {
+        self.set_secure(secure);
+        self
+    }
+
+    fn with_http_only(mut self, http_only: bool) -> Self {
+        self.set_http_only(http_only);
+        self
+    }
+
+    fn with_same_site(mut self, same_site: SameSite) -> Self {
+        self.set_same_site(same_site);
+        self
+    }
+}
@@ -0,0 +1,29 @@
+use crate::Key;
+
+/// Provides helpers for building key sequences.
+pub trait KeyHelper {
+    /// Build a key chord (keyboard shortcut) that can be passed to `WebElement::send_keys()`.
+    ///
+    /// When sent, the modifiers are pressed in order, then the key is typed. The sequence
+    /// ends with `Key::Null`, which releases all modifiers, so subsequent keys are typed
+    /// normally.
+    ///
+    /// # Example:
+    /// ```
+    /// use thirtyfour::prelude::*;
+    ///
+    /// // Ctrl+Shift+T
+    /// let chord = Key::chord(&[Key::Control, Key::Shift], 't');
+    /// assert_eq!(chord, Key::Control + &Key::Shift + "t" + &Key::Null);
+    /// ```
+    fn chord(modifiers: &[Key], key: char) -> String;
+}
+
+impl KeyHelper for Key {
+    fn chord(modifiers: &[Key], key: char) -> String {
+        let mut chord: String = modifiers.iter().map(|modifier| char::from(*modifier)).collect();
+        chord.push(key);
+        chord.push(char::from(Key::Null));
+        chord
+    }
+}
@@ -0,0 +1,103 @@
+use serde_json::Value;
+
+/// The log target for the WebDriver commands sent by thirtyfour.
+pub(crate) const LOG_TARGET: &str = "thirtyfour::commands";
+
+/// Scripts and files longer than this are not included in the log.
+const MAX_LOGGED_VALUE_LEN: usize = 500;
+
+/// Log the request body for the specified endpoint at `trace` level, with sensitive or large
+/// values redacted.
+///
+/// The body is only built if `trace` logging is enabled.
+pub(crate) fn trace_body(endpoint: &str, body: impl FnOnce() -> Value) {
+    if log::log_enabled!(target: LOG_TARGET, log::Level::Trace) {
+        log::trace!(target: LOG_TARGET, "{endpoint} {}", describe_body(endpoint, body()));
+    }
+}
+
+/// Get the command for a request path, i.e. the path without the base path (such as
+/// `/wd/hub`) and the session prefix.
+pub(crate) fn request_command(path: &str) -> &str {
+    let path = path.trim_start_matches('/');
+    let session = if path == "session" || path.starts_with("session/") {
+        Some(path)
+    } else {
+        path.find("/session/")
+            .map(|i| &path[i + 1..])
+            .or_else(|| path.ends_with("/session").then_some("session"))
+    };
+    match session {
+        // Skip `session/<id>/`.
+        Some(session) => session.splitn(3, '/').nth(2).unwrap_or("session"),
+        None => path,
+    }
+}
+
+/// Describe a request body for logging, with sensitive or large values redacted.
+///
+/// The endpoint is the method and the command, e.g. `POST element/<id>/value`.
+fn describe_body(endpoint: &str, mut body: Value) -> String {
+    if let Some(value) = body.pointer_mut("/cookie/value") {
+        *value = Value::from("<redacted>");
+    }
+    // Keys sent to an element may include passwords.
+    let command = endpoint.split_whitespace().last().unwrap_or_default();
+    if matches!(command.split('/').collect::<Vec<_>>()[..], ["element", _, "value"]) {
+        for key in ["text", "value"] {
+            if let Some(value) = body.get_mut(key) {
+                *value = Value::from("<redacted>");
+            }
+        }
+    }
+    for key in ["script", "file"] {
+        if let Some(value) = body.get_mut(key) {
+            let len = value.as_str().map(str::len).unwrap_or_default();
+            if len > MAX_LOGGED_VALUE_LEN {
+                *value = Value::from(format!("<{key} of {len} bytes>"));
+            }
+        }
+    }
+    body.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_request_command() {
+        assert_eq!(request_command("/session"), "session");
+        assert_eq!(request_command("/session/abc"), "session");
+        assert_eq!(request_command("/session/abc/element/e1/click"), "element/e1/click");
+        assert_eq!(request_command("/status"), "status");
+
+        // Selenium 3 style urls with a base path.
+        assert_eq!(request_command("/wd/hub/session"), "session");
+        assert_eq!(request_command("/wd/hub/session/abc"), "session");
+        assert_eq!(request_command("/wd/hub/session/abc/element/e1/click"), "element/e1/click");
+        assert_eq!(request_command("/wd/hub/status"), "wd/hub/status");
+    }
+
+    #[test]
+    fn test_describe_body_redacts() {
+        let body = json!({ "cookie": { "name": "session", "value": "secret" } });
+        let described: Value = serde_json::from_str(&describe_body("POST cookie", body)).unwrap();
+        assert_eq!(described, json!({ "cookie": { "name": "session", "value": "<redacted>" } }));
+
+        let body = json!({ "text": "hunter2", "value": ["h", "u"] });
+        let described: Value =
+            serde_json::from_str(&describe_body("POST element/e1/value", body)).unwrap();
+        assert_eq!(described, json!({ "text": "<redacted>", "value": "<redacted>" }));
+
+        let script = "x".repeat(MAX_LOGGED_VALUE_LEN + 1);
+        let body = json!({ "script": script, "args": [] });
+        let described: Value =
+            serde_json::from_str(&describe_body("POST execute/sync", body)).unwrap();
+        assert_eq!(described["script"], format!("<script of {} bytes>", script.len()));
+
+        let body = json!({ "script": "return 1;", "args": [] });
+        assert_eq!(describe_body("POST execute/sync", body.clone()), body.to_string());
+    }
+}
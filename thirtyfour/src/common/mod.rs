@@ -4,5 +4,17 @@ pub mod capabilities;
 pub mod command;
 /// Configuration options for a `WebDriver` instance.
 pub mod config;
+/// HTTP connector used for the connection to the WebDriver server.
+pub(crate) mod connector;
+/// Extensions for working with cookies.
+pub mod cookie;
+/// Extensions for working with keys.
+pub mod key;
+/// Logging for the commands sent to the WebDriver server.
+pub(crate) mod logging;
+/// Parameters for printing a page to PDF.
+pub mod print;
+/// Extensions for working with timeouts.
+pub mod timeouts;
 /// Common types used within thirtyfour.
 pub mod types;
@@ -0,0 +1,101 @@
+use serde::Serialize;
+
+/// The page orientation to use when printing.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PrintOrientation {
+    /// Portrait orientation (the default).
+    #[default]
+    Portrait,
+    /// Landscape orientation.
+    Landscape,
+}
+
+/// The page size to use when printing, in centimeters.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PrintPage {
+    /// The page width in centimeters. Defaults to 21.59 (US Letter).
+    pub width: f64,
+    /// The page height in centimeters. Defaults to 27.94 (US Letter).
+    pub height: f64,
+}
+
+impl Default for PrintPage {
+    fn default() -> Self {
+        Self {
+            width: 21.59,
+            height: 27.94,
+        }
+    }
+}
+
+/// The page margins to use when printing, in centimeters.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PrintMargins {
+    /// The top margin in centimeters. Defaults to 1.
+    pub top: f64,
+    /// The bottom margin in centimeters. Defaults to 1.
+    pub bottom: f64,
+    /// The left margin in centimeters. Defaults to 1.
+    pub left: f64,
+    /// The right margin in centimeters. Defaults to 1.
+    pub right: f64,
+}
+
+impl Default for PrintMargins {
+    fn default() -> Self {
+        Self {
+            top: 1.0,
+            bottom: 1.0,
+            left: 1.0,
+            right: 1.0,
+        }
+    }
+}
+
+/// Parameters for printing the current page to PDF, used by `WebDriver::print_page()`.
+///
+/// The defaults match those specified by the W3C WebDriver spec.
+///
+/// # Example:
+/// ```
+/// use thirtyfour::{PrintOrientation, PrintParameters};
+///
+/// let params = PrintParameters {
+///     orientation: PrintOrientation::Landscape,
+///     page_ranges: vec!["1-2".to_string()],
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrintParameters {
+    /// The page orientation.
+    pub orientation: PrintOrientation,
+    /// The scale of the page rendering. Must be between 0.1 and 2. Defaults to 1.
+    pub scale: f64,
+    /// Whether to print background colours and images. Defaults to false.
+    pub background: bool,
+    /// The page size.
+    pub page: PrintPage,
+    /// The page margins.
+    pub margin: PrintMargins,
+    /// Whether to shrink the content to fit the page width. Defaults to true.
+    pub shrink_to_fit: bool,
+    /// The page ranges to print, e.g. `"1-3"` or `"5"`. An empty list prints all pages.
+    pub page_ranges: Vec<String>,
+}
+
+impl Default for PrintParameters {
+    fn default() -> Self {
+        Self {
+            orientation: PrintOrientation::default(),
+            scale: 1.0,
+            background: false,
+            page: PrintPage::default(),
+            margin: PrintMargins::default(),
+            shrink_to_fit: true,
+            page_ranges: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use std::time::Duration;
+
+use crate::TimeoutConfiguration;
+
+/// Provides a builder and the W3C default values for `TimeoutConfiguration`.
+///
+/// # Example:
+/// ```
+/// use std::time::Duration;
+/// use thirtyfour::prelude::*;
+///
+/// let timeouts = TimeoutConfiguration::builder()
+///     .script(Duration::from_secs(10))
+///     .page_load(Duration::from_secs(120))
+///     .build();
+/// assert_eq!(timeouts.script(), Some(Duration::from_secs(10)));
+/// assert_eq!(timeouts.page_load(), Some(Duration::from_secs(120)));
+/// // Timeouts that are not set are left unchanged by `WebDriver::update_timeouts()`.
+/// assert_eq!(timeouts.implicit(), None);
+///
+/// let defaults = TimeoutConfiguration::w3c_defaults();
+/// assert_eq!(defaults.script(), Some(Duration::from_secs(30)));
+/// assert_eq!(defaults.page_load(), Some(Duration::from_secs(300)));
+/// assert_eq!(defaults.implicit(), Some(Duration::ZERO));
+/// ```
+pub trait TimeoutConfigurationHelper {
+    /// Create a builder with no timeouts set.
+    fn builder() -> TimeoutConfigurationBuilder;
+
+    /// The default timeouts in the W3C WebDriver specification: 30 seconds for scripts,
+    /// 300 seconds for page loads and 0 seconds for implicit waits.
+    ///
+    /// NOTE: `TimeoutConfiguration::default()` uses different values.
+    fn w3c_defaults() -> TimeoutConfiguration;
+}
+
+impl TimeoutConfigurationHelper for TimeoutConfiguration {
+    fn builder() -> TimeoutConfigurationBuilder {
+        TimeoutConfigurationBuilder::default()
+    }
+
+    fn w3c_defaults() -> TimeoutConfiguration {
+        TimeoutConfiguration::new(
+            Some(Duration::from_secs(30)),
+            Some(Duration::from_secs(300)),
+            Some(Duration::ZERO),
+        )
+    }
+}
+
+/// Builder for `TimeoutConfiguration`, created with `TimeoutConfiguration::builder()`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TimeoutConfigurationBuilder {
+    script: Option<Duration>,
+    page_load: Option<Duration>,
+    implicit: Option<Duration>,
+}
+
+impl TimeoutConfigurationBuilder {
+    /// Set the timeout for interrupting a script that is being evaluated.
+    pub fn script(mut self, timeout: Duration) -> Self {
+        self.script = Some(timeout);
+        self
+    }
+
+    /// Set the timeout for interrupting navigation of the browsing context.
+    pub fn page_load(mut self, timeout: Duration) -> Self {
+        self.page_load = Some(timeout);
+        self
+    }
+
+    /// Set the timeout for locating an element.
+    ///
+    /// NOTE: It is recommended to leave this at 0 seconds, and use `WebDriver::query()` to
+    ///       wait for elements instead.
+    pub fn implicit(mut self, timeout: Duration) -> Self {
+        self.implicit = Some(timeout);
+        self
+    }
+
+    /// Build the `TimeoutConfiguration`.
+    pub fn build(self) -> TimeoutConfiguration {
+        TimeoutConfiguration::new(self.script, self.page_load, self.implicit)
+    }
+}
+
+impl From<TimeoutConfigurationBuilder> for TimeoutConfiguration {
+    fn from(builder: TimeoutConfigurationBuilder) -> Self {
+        builder.build()
+    }
+}
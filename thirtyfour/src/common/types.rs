@@ -1,6 +1,8 @@
+use crate::session::handle::SessionHandle;
 use crate::{ElementRef, WebElement};
 use futures::future::BoxFuture;
 use std::fmt;
+use std::sync::Arc;
 
 use crate::error::WebDriverResult;
 use serde::{Deserialize, Serialize};
@@ -100,8 +102,21 @@ impl fmt::Display for SessionId {
     }
 }
 
+impl SessionId {
+    /// The session id as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.id
+    }
+}
+
+impl AsRef<str> for SessionId {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
 /// The window type. The webdriver spec treats tabs and windows as windows.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum WindowType {
     /// A browser tab.
     Tab,
@@ -122,6 +137,129 @@ impl fmt::Display for WindowType {
     }
 }
 
+impl From<fantoccini::wd::NewWindowType> for WindowType {
+    fn from(value: fantoccini::wd::NewWindowType) -> Self {
+        match value {
+            fantoccini::wd::NewWindowType::Tab => WindowType::Tab,
+            fantoccini::wd::NewWindowType::Window => WindowType::Window,
+        }
+    }
+}
+
+/// The type of log to retrieve, used by `WebDriver::get_log()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LogType {
+    /// The browser log, including the javascript console.
+    Browser,
+    /// The WebDriver log.
+    Driver,
+    /// The performance log, including network events (Chromium only).
+    Performance,
+    /// The client log.
+    Client,
+    /// Any other log type supported by the WebDriver.
+    Other(String),
+}
+
+impl fmt::Display for LogType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                LogType::Browser => "browser",
+                LogType::Driver => "driver",
+                LogType::Performance => "performance",
+                LogType::Client => "client",
+                LogType::Other(log_type) => log_type,
+            }
+        )
+    }
+}
+
+impl From<&str> for LogType {
+    fn from(value: &str) -> Self {
+        match value {
+            "browser" => LogType::Browser,
+            "driver" => LogType::Driver,
+            "performance" => LogType::Performance,
+            "client" => LogType::Client,
+            log_type => LogType::Other(log_type.to_string()),
+        }
+    }
+}
+
+impl Serialize for LogType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for LogType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let log_type = String::deserialize(deserializer)?;
+        Ok(LogType::from(log_type.as_str()))
+    }
+}
+
+/// A log entry, returned by `WebDriver::get_log()`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogEntry {
+    /// The log level, e.g. "SEVERE", "WARNING" or "INFO".
+    pub level: String,
+    /// The log message.
+    pub message: String,
+    /// The time of the log entry, in milliseconds since the Unix epoch.
+    pub timestamp: i64,
+}
+
+/// How to align an element when scrolling it into view, used by
+/// `WebElement::scroll_into_view_with()`.
+///
+/// See [scrollIntoView()](https://developer.mozilla.org/en-US/docs/Web/API/Element/scrollIntoView)
+/// for details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScrollAlignment {
+    /// Align the element with the top (or left) of the viewport.
+    Start,
+    /// Align the element with the center of the viewport.
+    Center,
+    /// Align the element with the bottom (or right) of the viewport.
+    End,
+    /// Only scroll if the element is not already visible, and then by as little as possible.
+    Nearest,
+}
+
+/// Selector for the frame to switch to, used by `WebDriver::switch_to_frame()`.
+#[derive(Debug, Clone)]
+pub enum FrameSelector {
+    /// The default (top-level) frame.
+    Default,
+    /// An iframe by index. The first iframe on the page has index 0.
+    Index(u16),
+    /// The iframe referenced by the specified element.
+    Element(WebElement),
+}
+
+impl From<u16> for FrameSelector {
+    fn from(index: u16) -> Self {
+        FrameSelector::Index(index)
+    }
+}
+
+impl From<WebElement> for FrameSelector {
+    fn from(element: WebElement) -> Self {
+        FrameSelector::Element(element)
+    }
+}
+
 /// Rectangle position and dimensions.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Rect {
@@ -145,6 +283,106 @@ impl Rect {
             height,
         }
     }
+
+    /// The coordinates of the rectangle center point.
+    pub fn center(&self) -> (f64, f64) {
+        (self.x as f64 + (self.width as f64 / 2.0), self.y as f64 + (self.height as f64 / 2.0))
+    }
+
+    /// Return true if the specified point is inside this rectangle.
+    ///
+    /// The top and left edges are inside the rectangle, but the bottom and right edges
+    /// are not, so adjacent rectangles never both contain the same point.
+    ///
+    /// # Example:
+    /// ```
+    /// use thirtyfour::Rect;
+    ///
+    /// let rect = Rect::new(10, 10, 100, 50);
+    /// assert!(rect.contains(10.0, 10.0));
+    /// assert!(rect.contains(109.5, 59.5));
+    /// assert!(!rect.contains(110.0, 30.0));
+    /// ```
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        x >= self.x as f64
+            && x < (self.x + self.width) as f64
+            && y >= self.y as f64
+            && y < (self.y + self.height) as f64
+    }
+
+    /// Return true if this rectangle overlaps the other rectangle.
+    ///
+    /// Rectangles that only share an edge do not overlap.
+    ///
+    /// # Example:
+    /// ```
+    /// use thirtyfour::Rect;
+    ///
+    /// let header = Rect::new(0, 0, 800, 100);
+    /// assert!(header.intersects(&Rect::new(700, 50, 200, 200)));
+    /// assert!(!header.intersects(&Rect::new(0, 100, 800, 500)));
+    /// ```
+    pub fn intersects(&self, other: &Rect) -> bool {
+        self.x < other.x + other.width
+            && other.x < self.x + self.width
+            && self.y < other.y + other.height
+            && other.y < self.y + self.height
+    }
+}
+
+/// Rectangle position and dimensions where any field can be omitted, used by
+/// `WebDriver::update_window_rect()`.
+///
+/// Omitted fields are sent as `null`, so the WebDriver leaves them unchanged.
+///
+/// # Example:
+/// ```
+/// use serde_json::json;
+/// use thirtyfour::OptionRect;
+///
+/// let rect = OptionRect::new().with_size(800, 600);
+/// assert_eq!(
+///     serde_json::to_value(rect).unwrap(),
+///     json!({ "x": null, "y": null, "width": 800, "height": 600 })
+/// );
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct OptionRect {
+    /// The x coordinate of the top-left corner.
+    pub x: Option<i64>,
+    /// The y coordinate of the top-left corner.
+    pub y: Option<i64>,
+    /// The rectangle width.
+    pub width: Option<i64>,
+    /// The rectangle height.
+    pub height: Option<i64>,
+}
+
+impl OptionRect {
+    /// Create a new `OptionRect` with all fields omitted.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the position of the top-left corner.
+    pub fn with_pos(mut self, x: i64, y: i64) -> Self {
+        self.x = Some(x);
+        self.y = Some(y);
+        self
+    }
+
+    /// Set the width and height.
+    pub fn with_size(mut self, width: i64, height: i64) -> Self {
+        self.width = Some(width);
+        self.height = Some(height);
+        self
+    }
+}
+
+impl From<Rect> for OptionRect {
+    fn from(rect: Rect) -> Self {
+        OptionRect::new().with_pos(rect.x, rect.y).with_size(rect.width, rect.height)
+    }
 }
 
 /// Generic element query function that returns some type T.
@@ -153,3 +391,13 @@ pub type ElementQueryFn<T> =
 
 /// Function signature for element predicates.
 pub type ElementPredicate = ElementQueryFn<bool>;
+
+/// Function signature for conditions used with `WebDriverWait::until()`.
+///
+/// The condition returns `Some(value)` once it is satisfied, or `None` to keep waiting.
+pub type WaitCondition<T> = Box<
+    dyn Fn(Arc<SessionHandle>) -> BoxFuture<'static, WebDriverResult<Option<T>>>
+        + Send
+        + Sync
+        + 'static,
+>;
@@ -74,7 +74,15 @@ pub struct SelectElement {
 
 impl SelectElement {
     /// Instantiate a new SelectElement struct. The specified element must be a `<select>` element.
+    ///
+    /// Returns `WebDriverError::InvalidArgument` if the element is not a `<select>` element.
     pub async fn new(element: &WebElement) -> WebDriverResult<SelectElement> {
+        let tag_name = element.tag_name().await?;
+        if !tag_name.eq_ignore_ascii_case("select") {
+            return Err(WebDriverError::InvalidArgument(WebDriverErrorDetails::new(format!(
+                "SelectElement requires a <select> element, got <{tag_name}>"
+            ))));
+        }
         let multiple = element.attr("multiple").await?.filter(|x| x != "false").is_some();
         let element = element.clone();
         Ok(SelectElement {
@@ -240,11 +240,57 @@ impl From<CmdError> for WebDriverError {
                     _ => WebDriverError::UnknownError(details),
                 }
             }
+            CmdError::Failed(e) if is_timeout(&e) => {
+                WebDriverError::Timeout(WebDriverErrorDetails::new(format!(
+                    "request to the WebDriver server timed out: {e}"
+                )))
+            }
             x => WebDriverError::Cmd(x),
         }
     }
 }
 
+/// Returns true if the error was caused by the connection to the WebDriver server being
+/// closed while the request was in flight, for example an idle keep-alive connection that
+/// was dropped by a proxy.
+pub(crate) fn is_connection_closed(e: &CmdError) -> bool {
+    let CmdError::Failed(e) = e else {
+        return false;
+    };
+    if e.is_incomplete_message() || e.is_closed() {
+        return true;
+    }
+    let mut source = e.source();
+    while let Some(e) = source {
+        if let Some(io_error) = e.downcast_ref::<std::io::Error>() {
+            if matches!(
+                io_error.kind(),
+                std::io::ErrorKind::ConnectionReset
+                    | std::io::ErrorKind::ConnectionAborted
+                    | std::io::ErrorKind::BrokenPipe
+            ) {
+                return true;
+            }
+        }
+        source = e.source();
+    }
+    false
+}
+
+/// Returns true if the error was caused by the request timeout of the HTTP connection.
+fn is_timeout(e: &(dyn Error + 'static)) -> bool {
+    let mut source = Some(e);
+    while let Some(e) = source {
+        if let Some(io_error) = e.downcast_ref::<std::io::Error>() {
+            if io_error.kind() == std::io::ErrorKind::TimedOut {
+                return true;
+            }
+        }
+        source = e.source();
+    }
+    false
+}
+
 impl From<url::ParseError> for WebDriverError {
     fn from(pe: url::ParseError) -> Self {
         Self::CustomError(format!("unable to parse url: {}", pe))
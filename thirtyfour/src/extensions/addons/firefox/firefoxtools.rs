@@ -48,8 +48,7 @@ impl FirefoxTools {
     /// Install the specified firefox add-on.
     pub async fn install_addon(&self, path: &str, temporary: Option<bool>) -> WebDriverResult<()> {
         self.handle
-            .client
-            .issue_cmd(FirefoxCommand::InstallAddon {
+            .cmd(FirefoxCommand::InstallAddon {
                 path: path.to_string(),
                 temporary,
             })
@@ -59,7 +58,7 @@ impl FirefoxTools {
 
     /// Take a full-page screenshot of the current window and return it as PNG bytes.
     pub async fn full_screenshot_as_png(&self) -> WebDriverResult<Vec<u8>> {
-        let src = self.handle.client.issue_cmd(FirefoxCommand::FullScreenshot {}).await?;
+        let src = self.handle.cmd(FirefoxCommand::FullScreenshot {}).await?;
         if let Some(src) = src.as_str() {
             let decoded = BASE64_STANDARD.decode(src)?;
             Ok(decoded)
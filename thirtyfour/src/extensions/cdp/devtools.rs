@@ -1,6 +1,6 @@
 use super::ChromeCommand;
-use super::NetworkConditions;
-use crate::error::WebDriverResult;
+use super::{NetworkConditions, PermissionState};
+use crate::error::{WebDriverError, WebDriverResult};
 use crate::session::handle::SessionHandle;
 use serde_json::{json, Value};
 use std::sync::Arc;
@@ -63,7 +63,7 @@ impl ChromeDevTools {
 
     /// Launch the Chrome app with the specified id.
     pub async fn launch_app(&self, app_id: &str) -> WebDriverResult<()> {
-        self.handle.client.issue_cmd(ChromeCommand::LaunchApp(app_id.to_string())).await?;
+        self.handle.cmd(ChromeCommand::LaunchApp(app_id.to_string())).await?;
         Ok(())
     }
 
@@ -96,7 +96,7 @@ impl ChromeDevTools {
     /// # }
     /// ```
     pub async fn get_network_conditions(&self) -> WebDriverResult<NetworkConditions> {
-        let v = self.handle.client.issue_cmd(ChromeCommand::GetNetworkConditions).await?;
+        let v = self.handle.cmd(ChromeCommand::GetNetworkConditions).await?;
         let conditions: NetworkConditions = serde_json::from_value(v)?;
         Ok(conditions)
     }
@@ -138,10 +138,7 @@ impl ChromeDevTools {
         &self,
         conditions: &NetworkConditions,
     ) -> WebDriverResult<()> {
-        self.handle
-            .client
-            .issue_cmd(ChromeCommand::SetNetworkConditions(conditions.clone()))
-            .await?;
+        self.handle.cmd(ChromeCommand::SetNetworkConditions(conditions.clone())).await?;
         Ok(())
     }
 
@@ -215,34 +212,297 @@ impl ChromeDevTools {
 
     /// Get the list of sinks available for cast.
     pub async fn get_sinks(&self) -> WebDriverResult<Value> {
-        let v = self.handle.client.issue_cmd(ChromeCommand::GetSinks).await?;
+        let v = self.handle.cmd(ChromeCommand::GetSinks).await?;
         Ok(v)
     }
 
     /// Get the issue message for any issue in a cast session.
     pub async fn get_issue_message(&self) -> WebDriverResult<Value> {
-        let v = self.handle.client.issue_cmd(ChromeCommand::GetIssueMessage).await?;
+        let v = self.handle.cmd(ChromeCommand::GetIssueMessage).await?;
         Ok(v)
     }
 
     /// Set the specified sink as the cast session receiver target.
     pub async fn set_sink_to_use(&self, sink_name: &str) -> WebDriverResult<()> {
-        self.handle.client.issue_cmd(ChromeCommand::SetSinkToUse(sink_name.to_string())).await?;
+        self.handle.cmd(ChromeCommand::SetSinkToUse(sink_name.to_string())).await?;
         Ok(())
     }
 
     /// Start a tab mirroring session on the specified receiver target.
     pub async fn start_tab_mirroring(&self, sink_name: &str) -> WebDriverResult<()> {
-        self.handle
-            .client
-            .issue_cmd(ChromeCommand::StartTabMirroring(sink_name.to_string()))
-            .await?;
+        self.handle.cmd(ChromeCommand::StartTabMirroring(sink_name.to_string())).await?;
         Ok(())
     }
 
     /// Stop the existing cast session on the specified receiver target.
     pub async fn stop_casting(&self, sink_name: &str) -> WebDriverResult<()> {
-        self.handle.client.issue_cmd(ChromeCommand::StopCasting(sink_name.to_string())).await?;
+        self.handle.cmd(ChromeCommand::StopCasting(sink_name.to_string())).await?;
+        Ok(())
+    }
+}
+
+/// Return true if the specified browser name is a Chromium-based browser.
+fn is_chromium(browser_name: &str) -> bool {
+    let name = browser_name.to_lowercase();
+    ["chrome", "chromium", "edge", "opera", "brave"].iter().any(|x| name.contains(x))
+}
+
+impl SessionHandle {
+    /// Execute the specified Chrome DevTools Protocol command with the specified parameters,
+    /// and return the result.
+    ///
+    /// This is only supported by Chromium-based browsers (e.g. Chrome and Edge). For other
+    /// browsers an error is returned. See [`ChromeDevTools`] for more Chrome-specific
+    /// commands.
+    ///
+    /// You can find documentation for the available commands here:
+    /// [https://chromedevtools.github.io/devtools-protocol/](https://chromedevtools.github.io/devtools-protocol/])
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// use serde_json::json;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// driver.execute_cdp_command("Network.setCacheDisabled", json!({"cacheDisabled": true})).await?;
+    /// let version_info = driver.execute_cdp_command("Browser.getVersion", json!({})).await?;
+    /// let user_agent = version_info["userAgent"].as_str().unwrap();
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn execute_cdp_command(
+        self: &Arc<SessionHandle>,
+        cmd: &str,
+        params: Value,
+    ) -> WebDriverResult<Value> {
+        self.issue_chromium_cmd(ChromeCommand::ExecuteCdpCommand(cmd.to_string(), params)).await
+    }
+
+    /// Issue the specified Chromium-specific command, returning a clear error if the browser
+    /// for this session is not Chromium-based.
+    async fn issue_chromium_cmd(
+        self: &Arc<SessionHandle>,
+        cmd: ChromeCommand,
+    ) -> WebDriverResult<Value> {
+        let browser_name = self.browser_name();
+        let unsupported = || {
+            WebDriverError::CustomError(format!(
+                "this command is only supported by Chromium-based browsers, \
+                but the browser for this session is '{}'",
+                browser_name.as_deref().unwrap_or("unknown")
+            ))
+        };
+        if browser_name.as_deref().is_some_and(|x| !is_chromium(x)) {
+            return Err(unsupported());
+        }
+
+        match self.cmd(cmd).await {
+            Err(
+                WebDriverError::UnknownCommand(_)
+                | WebDriverError::UnknownMethod(_)
+                | WebDriverError::UnknownPath(_),
+            ) => Err(unsupported()),
+            result => result,
+        }
+    }
+
+    /// Simulate the specified network conditions, e.g. to test loading states.
+    ///
+    /// The latency is added to each request, in milliseconds. The download and upload
+    /// throughput are in bytes/second, and -1 disables throttling. Values that are out of
+    /// range for the browser are clamped. Use `apply_network_conditions()` for presets such
+    /// as offline and slow 3G. This is only supported by Chromium-based browsers
+    /// (e.g. Chrome and Edge).
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// // Add 500ms of latency and limit the download speed to 100KB/s.
+    /// driver.set_network_conditions(false, 500, 100 * 1024, -1).await?;
+    /// driver.goto("https://www.rust-lang.org/").await?;
+    ///
+    /// // Disable throttling again.
+    /// driver.set_network_conditions(false, 0, -1, -1).await?;
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn set_network_conditions(
+        self: &Arc<SessionHandle>,
+        offline: bool,
+        latency_ms: u64,
+        download_bps: i64,
+        upload_bps: i64,
+    ) -> WebDriverResult<()> {
+        let throughput = |bps: i64| i32::try_from(bps.max(-1)).unwrap_or(i32::MAX);
+        let conditions = NetworkConditions {
+            offline,
+            latency: u32::try_from(latency_ms).unwrap_or(u32::MAX),
+            download_throughput: throughput(download_bps),
+            upload_throughput: throughput(upload_bps),
+            connection_type: None,
+        };
+        self.apply_network_conditions(&conditions).await
+    }
+
+    /// Simulate the specified network conditions.
+    ///
+    /// See [`NetworkConditions`] for presets such as offline and slow 3G, and
+    /// `set_network_conditions()` for more details.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// use thirtyfour::extensions::cdp::NetworkConditions;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// driver.apply_network_conditions(&NetworkConditions::slow_3g()).await?;
+    /// driver.goto("https://www.rust-lang.org/").await?;
+    ///
+    /// // Disable throttling again.
+    /// driver.apply_network_conditions(&NetworkConditions::new()).await?;
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn apply_network_conditions(
+        self: &Arc<SessionHandle>,
+        conditions: &NetworkConditions,
+    ) -> WebDriverResult<()> {
+        self.issue_chromium_cmd(ChromeCommand::SetNetworkConditions(conditions.clone())).await?;
+        Ok(())
+    }
+
+    /// Override the geolocation reported by the browser.
+    ///
+    /// The `accuracy` is in meters. Use `clear_geolocation()` to remove the override.
+    /// This uses the Chrome DevTools Protocol, so it is only supported by Chromium-based
+    /// browsers (e.g. Chrome and Edge).
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// // Pretend to be in Sydney.
+    /// driver.set_geolocation(-33.8688, 151.2093, 100.0).await?;
+    /// driver.goto("https://www.rust-lang.org/").await?;
+    /// driver.clear_geolocation().await?;
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn set_geolocation(
+        self: &Arc<SessionHandle>,
+        latitude: f64,
+        longitude: f64,
+        accuracy: f64,
+    ) -> WebDriverResult<()> {
+        let params = json!({ "latitude": latitude, "longitude": longitude, "accuracy": accuracy });
+        self.execute_cdp_command("Emulation.setGeolocationOverride", params).await?;
+        Ok(())
+    }
+
+    /// Remove the geolocation override set by `set_geolocation()`.
+    ///
+    /// This is only supported by Chromium-based browsers (e.g. Chrome and Edge).
+    pub async fn clear_geolocation(self: &Arc<SessionHandle>) -> WebDriverResult<()> {
+        self.execute_cdp_command("Emulation.clearGeolocationOverride", json!({})).await?;
+        Ok(())
+    }
+
+    /// Set the state of the specified permission (e.g. `clipboard-read` or `notifications`)
+    /// for the origin of the current page.
+    ///
+    /// The permission only applies to pages with the same origin as the current page, so
+    /// navigate to the site under test first. If the current page has no origin (e.g.
+    /// `about:blank`), the permission applies to all origins. This uses the Chrome DevTools
+    /// Protocol, so it is only supported by Chromium-based browsers (e.g. Chrome and Edge).
+    ///
+    /// The permission names are listed here:
+    /// [https://chromedevtools.github.io/devtools-protocol/tot/Browser/#type-PermissionDescriptor](https://chromedevtools.github.io/devtools-protocol/tot/Browser/#type-PermissionDescriptor)
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// use thirtyfour::extensions::cdp::PermissionState;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// driver.goto("https://www.rust-lang.org/").await?;
+    /// driver.set_permission("notifications", PermissionState::Denied).await?;
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn set_permission(
+        self: &Arc<SessionHandle>,
+        name: &str,
+        state: PermissionState,
+    ) -> WebDriverResult<()> {
+        let mut params = json!({ "permission": { "name": name }, "setting": state });
+        let origin = self.current_url().await?.origin();
+        if origin.is_tuple() {
+            params["origin"] = json!(origin.ascii_serialization());
+        }
+        self.execute_cdp_command("Browser.setPermission", params).await?;
+        Ok(())
+    }
+
+    /// Grant the specified permissions for the origin of the current page.
+    ///
+    /// See [`SessionHandle::set_permission()`] for details.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// driver.goto("https://www.rust-lang.org/").await?;
+    /// driver.grant_permissions(&["clipboard-read", "clipboard-write"]).await?;
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn grant_permissions(
+        self: &Arc<SessionHandle>,
+        names: &[&str],
+    ) -> WebDriverResult<()> {
+        for name in names {
+            self.set_permission(name, PermissionState::Granted).await?;
+        }
         Ok(())
     }
 }
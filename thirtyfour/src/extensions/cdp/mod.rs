@@ -1,7 +1,9 @@
 mod chromecommand;
 mod devtools;
 mod networkconditions;
+mod permissions;
 
 pub use chromecommand::ChromeCommand;
 pub use devtools::ChromeDevTools;
 pub use networkconditions::NetworkConditions;
+pub use permissions::PermissionState;
@@ -54,4 +54,29 @@ impl NetworkConditions {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Network conditions that simulate being offline.
+    pub fn offline() -> Self {
+        Self {
+            offline: true,
+            latency: 0,
+            download_throughput: 0,
+            upload_throughput: 0,
+            connection_type: None,
+        }
+    }
+
+    /// Network conditions that simulate a slow 3G connection.
+    ///
+    /// This matches the "Slow 3G" preset in Chrome DevTools: 2000ms latency and
+    /// 50,000 bytes/second in each direction.
+    pub fn slow_3g() -> Self {
+        Self {
+            offline: false,
+            latency: 2000,
+            download_throughput: 50_000,
+            upload_throughput: 50_000,
+            connection_type: None,
+        }
+    }
 }
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// The state of a browser permission, for use with `WebDriver::set_permission()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PermissionState {
+    /// The permission is granted without prompting.
+    Granted,
+    /// The permission is denied without prompting.
+    Denied,
+    /// The user is prompted, which is the default for most permissions.
+    Prompt,
+}
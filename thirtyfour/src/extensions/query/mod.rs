@@ -124,6 +124,31 @@
 //!
 //! These predicates (or your own) can also be supplied as filters to `ElementQuery`.
 //!
+//! ### WebDriverWait
+//!
+//! To wait for a condition that does not relate to a single element, such as the page title,
+//! use [`WebDriverWait`]. The condition returns `Some(value)` once it is satisfied.
+//!
+//! ```ignore
+//! let wait = WebDriverWait::new(&driver, Duration::from_secs(10), Duration::from_millis(500));
+//! let title = wait.until(|handle| async move {
+//!     let title = handle.title().await?;
+//!     Ok(title.contains("Rust").then_some(title))
+//! }).await?;
+//! ```
+//!
+//! A range of pre-defined conditions for use with `WebDriverWait` are supplied in the
+//! `thirtyfour::extensions::query::wait_conditions` module.
+//!
+//! ```ignore
+//! use thirtyfour::extensions::query::wait_conditions;
+//!
+//! let button = wait.until(wait_conditions::element_is_present(By::Id("button1"))).await?;
+//! wait.until(wait_conditions::element_is_clickable(button)).await?;
+//! ```
+//!
+//! [`WebDriverWait`]: crate::extensions::query::WebDriverWait
+//!
 //! ### ElementPoller
 //!
 //! The polling strategy can be customized by implementing both [`ElementPoller`]
@@ -140,6 +165,10 @@ pub mod conditions;
 mod element_query;
 mod element_waiter;
 mod poller;
+/// Conditions to use with `WebDriverWait`.
+pub mod wait_conditions;
+mod webdriver_wait;
 pub use element_query::*;
 pub use element_waiter::*;
 pub use poller::*;
+pub use webdriver_wait::*;
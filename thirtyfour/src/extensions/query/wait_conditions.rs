@@ -0,0 +1,73 @@
+use crate::error::{WebDriverError, WebDriverResult};
+use crate::{By, WaitCondition, WebElement};
+
+/// Treat stale element errors as "not yet satisfied" rather than returning the error.
+fn ignore_stale<T>(result: WebDriverResult<Option<T>>) -> WebDriverResult<Option<T>> {
+    match result {
+        Err(WebDriverError::StaleElementReference(_)) => Ok(None),
+        x => x,
+    }
+}
+
+/// Condition that is satisfied once an element matching the selector is present.
+///
+/// Returns the element that was found.
+pub fn element_is_present(by: impl Into<By>) -> WaitCondition<WebElement> {
+    let by: By = by.into();
+    Box::new(move |handle| {
+        let by = by.clone();
+        Box::pin(async move {
+            match handle.find(by).await {
+                Ok(elem) => Ok(Some(elem)),
+                Err(WebDriverError::NoSuchElement(_)) => Ok(None),
+                Err(e) => Err(e),
+            }
+        })
+    })
+}
+
+/// Condition that is satisfied once the element is displayed.
+///
+/// Returns the element.
+pub fn element_is_displayed(elem: WebElement) -> WaitCondition<WebElement> {
+    Box::new(move |_| {
+        let elem = elem.clone();
+        Box::pin(async move { ignore_stale(elem.is_displayed().await.map(|x| x.then_some(elem))) })
+    })
+}
+
+/// Condition that is satisfied once the element is clickable.
+///
+/// Returns the element.
+pub fn element_is_clickable(elem: WebElement) -> WaitCondition<WebElement> {
+    Box::new(move |_| {
+        let elem = elem.clone();
+        Box::pin(async move { ignore_stale(elem.is_clickable().await.map(|x| x.then_some(elem))) })
+    })
+}
+
+/// Condition that is satisfied once the page title contains the specified text.
+///
+/// Returns the page title.
+pub fn title_contains(text: impl Into<String>) -> WaitCondition<String> {
+    let text: String = text.into();
+    Box::new(move |handle| {
+        let text = text.clone();
+        Box::pin(async move {
+            let title = handle.title().await?;
+            Ok(title.contains(&text).then_some(title))
+        })
+    })
+}
+
+/// Condition that is satisfied once the current URL is exactly the specified URL.
+pub fn url_is(url: impl Into<String>) -> WaitCondition<()> {
+    let url: String = url.into();
+    Box::new(move |handle| {
+        let url = url.clone();
+        Box::pin(async move {
+            let current_url = handle.current_url().await?;
+            Ok((current_url.as_str() == url).then_some(()))
+        })
+    })
+}
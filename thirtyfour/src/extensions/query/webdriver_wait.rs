@@ -0,0 +1,94 @@
+use super::{ElementPollerWithTimeout, IntoElementPoller};
+use crate::error::{WebDriverError, WebDriverErrorDetails, WebDriverResult};
+use crate::session::handle::SessionHandle;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Explicit wait for an arbitrary condition on the WebDriver session.
+///
+/// The condition is a closure that receives the session handle and returns
+/// `Ok(Some(value))` once it is satisfied, or `Ok(None)` if it should be retried.
+/// Any error returned by the condition will be returned immediately.
+///
+/// If the condition is not satisfied before the timeout, `WebDriverError::Timeout`
+/// is returned.
+///
+/// To wait for conditions on a specific element, see [`ElementWaiter`] instead.
+///
+/// [`ElementWaiter`]: super::ElementWaiter
+///
+/// # Example:
+/// ```no_run
+/// # use thirtyfour::prelude::*;
+/// # use thirtyfour::support::block_on;
+/// use std::time::Duration;
+/// use thirtyfour::extensions::query::WebDriverWait;
+/// #
+/// # fn main() -> WebDriverResult<()> {
+/// #     block_on(async {
+/// #         let caps = DesiredCapabilities::chrome();
+/// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+/// let wait = WebDriverWait::new(&driver, Duration::from_secs(10), Duration::from_millis(500));
+/// let title = wait
+///     .until(|handle| async move {
+///         let title = handle.title().await?;
+///         Ok(title.starts_with("Rust").then_some(title))
+///     })
+///     .await?;
+/// #         driver.quit().await?;
+/// #         Ok(())
+/// #     })
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct WebDriverWait {
+    handle: Arc<SessionHandle>,
+    poller: Arc<dyn IntoElementPoller + Send + Sync>,
+    message: String,
+}
+
+impl WebDriverWait {
+    /// Create a new `WebDriverWait` that polls the condition once per `interval`,
+    /// until the `timeout` elapses.
+    pub fn new(handle: &Arc<SessionHandle>, timeout: Duration, interval: Duration) -> Self {
+        Self {
+            handle: handle.clone(),
+            poller: Arc::new(ElementPollerWithTimeout::new(timeout, interval)),
+            message: String::new(),
+        }
+    }
+
+    /// Use the specified ElementPoller for this WebDriverWait.
+    pub fn with_poller(mut self, poller: Arc<dyn IntoElementPoller + Send + Sync>) -> Self {
+        self.poller = poller;
+        self
+    }
+
+    /// Provide a human-readable error message to be returned in the case of timeout.
+    pub fn error(mut self, message: &str) -> Self {
+        self.message = message.to_string();
+        self
+    }
+
+    /// Wait until the specified condition returns `Some(value)`, and return the value.
+    pub async fn until<F, Fut, T>(&self, condition: F) -> WebDriverResult<T>
+    where
+        F: Fn(Arc<SessionHandle>) -> Fut,
+        Fut: Future<Output = WebDriverResult<Option<T>>>,
+    {
+        let mut poller = self.poller.start();
+        loop {
+            if let Some(value) = condition(self.handle.clone()).await? {
+                return Ok(value);
+            }
+
+            if !poller.tick().await {
+                return Err(WebDriverError::Timeout(WebDriverErrorDetails::new(format!(
+                    "condition timed out: {}",
+                    self.message
+                ))));
+            }
+        }
+    }
+}
@@ -47,3 +47,113 @@ function simulateDragDrop(sourceNode, destinationNode) {
 }
 
 simulateDragDrop(arguments[0], arguments[1]);"#;
+
+/// A javascript function for finding elements by their position relative to other elements.
+///
+/// The first argument is the locator (using, value) and a list of filters, each containing
+/// the kind of relation, the anchor element, and the maximum distance (for "near" only).
+pub const FIND_RELATIVE_ELEMENTS: &str = r#"
+const locator = arguments[0];
+let candidates = [];
+if (locator.using === "css selector") {
+    candidates = Array.from(document.querySelectorAll(locator.value));
+} else if (locator.using === "xpath") {
+    const result = document.evaluate(
+        locator.value, document, null, XPathResult.ORDERED_NODE_SNAPSHOT_TYPE, null
+    );
+    for (let i = 0; i < result.snapshotLength; i++) {
+        candidates.push(result.snapshotItem(i));
+    }
+} else if (locator.using === "link text") {
+    candidates = Array.from(document.querySelectorAll("a"))
+        .filter(a => a.innerText.trim() === locator.value);
+}
+
+function gap(r, a) {
+    const dx = Math.max(a.left - r.right, r.left - a.right, 0);
+    const dy = Math.max(a.top - r.bottom, r.top - a.bottom, 0);
+    return Math.sqrt(dx * dx + dy * dy);
+}
+
+function matches(elem, filter) {
+    if (elem === filter.anchor) {
+        return false;
+    }
+    const r = elem.getBoundingClientRect();
+    const a = filter.anchor.getBoundingClientRect();
+    switch (filter.kind) {
+        case "above": return r.bottom <= a.top;
+        case "below": return r.top >= a.bottom;
+        case "left": return r.right <= a.left;
+        case "right": return r.left >= a.right;
+        case "near": return gap(r, a) <= filter.distance;
+    }
+    return false;
+}
+
+let found = candidates.filter(elem => locator.filters.every(f => matches(elem, f)));
+if (locator.filters.length > 0) {
+    // Sort by proximity to the first anchor element.
+    const a = locator.filters[0].anchor.getBoundingClientRect();
+    const ax = a.left + a.width / 2;
+    const ay = a.top + a.height / 2;
+    const distance = elem => {
+        const r = elem.getBoundingClientRect();
+        return Math.hypot(r.left + r.width / 2 - ax, r.top + r.height / 2 - ay);
+    };
+    found.sort((x, y) => distance(x) - distance(y));
+}
+return found;
+"#;
+
+/// Wrap a script that returns a value or a promise, so that it can be passed to
+/// execute_async.
+///
+/// The script runs in an async function with the same arguments, so it can use `await`.
+/// The callback receives either `{ value }` or `{ error }` if the promise was rejected.
+pub fn wrap_promise_script(script: &str) -> String {
+    format!(
+        r#"
+const done = arguments[arguments.length - 1];
+const args = Array.prototype.slice.call(arguments, 0, -1);
+(async function() {{
+{script}
+}}).apply(this, args).then(
+    (value) => done({{ value: value === undefined ? null : value }}),
+    (e) => done({{ error: e instanceof Error ? `${{e.name}}: ${{e.message}}` : String(e) }})
+);
+"#
+    )
+}
+
+/// The property of `window` where atoms are defined, keyed by name.
+const ATOMS_PROPERTY: &str = "__thirtyfour_atoms";
+
+/// Define the named atom on `window`, with the specified script as the function body,
+/// and then call it.
+///
+/// Returns `{ value }` with the value returned by the atom.
+pub fn define_atom_script(name: &str, script: &str) -> String {
+    format!(
+        r#"
+const atoms = window.{ATOMS_PROPERTY} = window.{ATOMS_PROPERTY} || {{}};
+atoms[{name:?}] = function() {{
+{script}
+}};
+return {{ value: atoms[{name:?}].apply(this, arguments) }};
+"#
+    )
+}
+
+/// Call the named atom, if it has been defined on `window`.
+///
+/// Returns `{ value }` with the value returned by the atom, or `{ missing: true }` if the
+/// atom is not defined, for example because the page has changed since it was defined.
+pub fn invoke_atom_script(name: &str) -> String {
+    format!(
+        r#"
+const atom = window.{ATOMS_PROPERTY} && window.{ATOMS_PROPERTY}[{name:?}];
+return atom ? {{ value: atom.apply(this, arguments) }} : {{ missing: true }};
+"#
+    )
+}
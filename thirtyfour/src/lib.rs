@@ -28,6 +28,11 @@
 //! * `rustls-tls`: (Default) Use rustls to provide TLS support (via fantoccini/hyper).
 //! * `native-tls`: Use native TLS (via fantoccini/hyper).
 //! * `component`: (Default) Enable the `Component` derive macro (via thirtyfour-macros).
+//! * `image`: Enable full page screenshots, and screenshots as `image::DynamicImage`
+//!   (via the `image` crate).
+//! * `sync`: Enable the blocking `WebDriver` and `WebElement` wrappers in the `sync` module.
+//! * `test-support`: Enable the `test_support` module, including an in-memory mock
+//!   WebDriver server for unit testing without a browser.
 //!
 //! ## Example
 //!
@@ -132,6 +137,16 @@
 //!
 //! See the [`components`] documentation for more details.
 //!
+//! ### Logging the WebDriver commands
+//!
+//! Every command sent to the WebDriver server is logged via the [`log`](https://docs.rs/log)
+//! crate, using the `thirtyfour::commands` target. At `debug` level this includes the
+//! command name, the endpoint, whether it failed and the elapsed time, and at `trace`
+//! level the request body for scripts, cookies and thirtyfour-specific commands is also
+//! logged. Cookie values and typed keys are redacted, and long scripts are omitted.
+//!
+//! For example, with `env_logger` set `RUST_LOG=thirtyfour::commands=debug`.
+//!
 //! ### See Also
 //!
 //! This crate uses [`fantoccini`] to communicate with the underlying `WebDriver`
@@ -151,7 +166,9 @@ pub use alert::Alert;
 pub use common::{
     capabilities::{
         chrome::ChromeCapabilities,
-        chromium::{ChromiumCapabilities, ChromiumLikeCapabilities},
+        chromium::{
+            ChromiumCapabilities, ChromiumLikeCapabilities, DeviceMetrics, MobileEmulation,
+        },
         desiredcapabilities::*,
         edge::EdgeCapabilities,
         firefox::FirefoxCapabilities,
@@ -159,9 +176,16 @@ pub use common::{
         opera::OperaCapabilities,
         safari::SafariCapabilities,
     },
-    command::By,
+    command::{By, RelativeBy},
+    cookie::CookieHelper,
+    key::KeyHelper,
+    print::*,
+    timeouts::{TimeoutConfigurationBuilder, TimeoutConfigurationHelper},
     types::*,
 };
+pub use pool::{PooledWebDriver, ReusePolicy, WebDriverPool};
+pub use shadowroot::ShadowRoot;
+pub use storage::Storage;
 pub use switch_to::SwitchTo;
 pub use webdriver::WebDriver;
 pub use webelement::WebElement;
@@ -171,15 +195,19 @@ pub mod prelude {
     pub use crate::alert::Alert;
     pub use crate::error::{WebDriverError, WebDriverResult};
     pub use crate::extensions::query::{ElementPoller, ElementQueryable, ElementWaitable};
+    pub use crate::session::scriptargs::ScriptArgs;
     pub use crate::session::scriptret::ScriptRet;
     pub use crate::switch_to::SwitchTo;
     pub use crate::WebDriver;
     pub use crate::WebElement;
     pub use crate::{
         BrowserCapabilitiesHelper, By, Capabilities, CapabilitiesHelper, ChromiumLikeCapabilities,
-        DesiredCapabilities,
+        DesiredCapabilities, FrameSelector, WindowType,
+    };
+    pub use crate::{
+        Cookie, CookieHelper, Key, KeyHelper, TimeoutConfiguration, TimeoutConfigurationHelper,
+        WindowHandle,
     };
-    pub use crate::{Cookie, Key, TimeoutConfiguration, WindowHandle};
 }
 
 /// Action chains allow for more complex user interactions with the keyboard and mouse.
@@ -194,12 +222,22 @@ pub mod components;
 pub mod error;
 /// Extensions for specific browsers.
 pub mod extensions;
+/// Pooling of WebDriver sessions, for running tests in parallel.
+pub mod pool;
 /// Everything related to driving the underlying WebDriver session.
 pub mod session;
+/// Web Storage (`localStorage` and `sessionStorage`) helpers.
+pub mod storage;
 /// Miscellaneous support functions for `thirtyfour` tests.
 pub mod support;
+#[cfg(feature = "sync")]
+pub mod sync;
+#[cfg(feature = "test-support")]
+pub mod test_support;
 
+mod clipboard;
 mod js;
+mod shadowroot;
 mod switch_to;
 mod upstream;
 mod webdriver;
@@ -213,3 +251,12 @@ pub use crate::upstream::*;
 
 // Re-export cookie crate.
 pub use cookie;
+
+// Re-export hyper crate, for custom connectors.
+// NOTE: This makes the hyper version part of the public API, so bumping hyper to a new
+//       major version is a breaking change.
+pub use hyper;
+
+// Re-export image crate.
+#[cfg(feature = "image")]
+pub use image;
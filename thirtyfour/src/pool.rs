@@ -0,0 +1,216 @@
+use crate::common::config::WebDriverConfig;
+use crate::error::{WebDriverError, WebDriverResult};
+use crate::{Capabilities, WebDriver};
+use parking_lot::Mutex;
+use std::ops::Deref;
+use std::sync::Arc;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// What to do with a session when it is returned to a [`WebDriverPool`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReusePolicy {
+    /// Keep the session open and hand it out again on the next `acquire()`.
+    ///
+    /// This is faster, but state such as cookies and open windows is shared between
+    /// the users of the session.
+    #[default]
+    Reuse,
+    /// End the session, so that every `acquire()` gets a fresh browser.
+    Recreate,
+}
+
+#[derive(Debug)]
+struct PoolInner {
+    server_url: String,
+    capabilities: Capabilities,
+    config: WebDriverConfig,
+    policy: ReusePolicy,
+    semaphore: Arc<Semaphore>,
+    idle: Mutex<Vec<WebDriver>>,
+}
+
+/// A bounded pool of `WebDriver` sessions on the same WebDriver server.
+///
+/// Sessions are created on demand, up to `max_sessions` at a time. Each call to
+/// [`WebDriverPool::acquire()`] waits until a session is available, and the session
+/// is returned to the pool when the [`PooledWebDriver`] guard is dropped.
+///
+/// This is useful for running tests in parallel against a Selenium grid.
+///
+/// # Example:
+/// ```no_run
+/// # use thirtyfour::prelude::*;
+/// # use thirtyfour::support::block_on;
+/// use thirtyfour::WebDriverPool;
+///
+/// # fn main() -> WebDriverResult<()> {
+/// #     block_on(async {
+/// let pool = WebDriverPool::new("http://localhost:4444", DesiredCapabilities::chrome(), 4);
+/// let tasks: Vec<_> = (0..10)
+///     .map(|_| {
+///         let pool = pool.clone();
+///         tokio::spawn(async move {
+///             let driver = pool.acquire().await?;
+///             driver.goto("https://www.rust-lang.org/").await?;
+///             driver.title().await
+///         })
+///     })
+///     .collect();
+/// for task in tasks {
+///     println!("{}", task.await.unwrap()?);
+/// }
+/// pool.close().await?;
+/// #         Ok(())
+/// #     })
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct WebDriverPool {
+    inner: Arc<PoolInner>,
+}
+
+impl WebDriverPool {
+    /// Create a new pool of up to `max_sessions` sessions with the specified capabilities.
+    ///
+    /// No sessions are created until they are acquired.
+    pub fn new<C>(server_url: &str, capabilities: C, max_sessions: usize) -> Self
+    where
+        C: Into<Capabilities>,
+    {
+        Self::new_with_config(
+            server_url,
+            capabilities,
+            WebDriverConfig::default(),
+            max_sessions,
+            ReusePolicy::default(),
+        )
+    }
+
+    /// Create a new pool, using the specified configuration and reuse policy for every session.
+    pub fn new_with_config<C>(
+        server_url: &str,
+        capabilities: C,
+        config: WebDriverConfig,
+        max_sessions: usize,
+        policy: ReusePolicy,
+    ) -> Self
+    where
+        C: Into<Capabilities>,
+    {
+        Self {
+            inner: Arc::new(PoolInner {
+                server_url: server_url.to_string(),
+                capabilities: capabilities.into(),
+                config,
+                policy,
+                semaphore: Arc::new(Semaphore::new(max_sessions)),
+                idle: Mutex::new(Vec::new()),
+            }),
+        }
+    }
+
+    /// Wait for a session to become available and return it.
+    ///
+    /// An idle session is reused if there is one, otherwise a new session is created.
+    /// Returns an error if the pool has been closed or the session could not be created.
+    pub async fn acquire(&self) -> WebDriverResult<PooledWebDriver> {
+        let permit =
+            self.inner.semaphore.clone().acquire_owned().await.map_err(|_| {
+                WebDriverError::CustomError("the WebDriverPool is closed".to_string())
+            })?;
+        let idle = self.inner.idle.lock().pop();
+        let driver = match idle {
+            Some(driver) => driver,
+            None => {
+                WebDriver::new_with_config(
+                    &self.inner.server_url,
+                    self.inner.capabilities.clone(),
+                    self.inner.config.clone(),
+                )
+                .await?
+            }
+        };
+        Ok(PooledWebDriver {
+            driver: Some(driver),
+            pool: self.inner.clone(),
+            permit: Some(permit),
+        })
+    }
+
+    /// The number of sessions that are not in use but are still open.
+    pub fn idle_sessions(&self) -> usize {
+        self.inner.idle.lock().len()
+    }
+
+    /// Close the pool and end all idle sessions.
+    ///
+    /// Any further calls to `acquire()` will return an error, and sessions that are
+    /// still in use will be ended when they are returned.
+    pub async fn close(&self) -> WebDriverResult<()> {
+        self.inner.semaphore.close();
+        let idle: Vec<WebDriver> = std::mem::take(&mut *self.inner.idle.lock());
+        for driver in idle {
+            driver.quit().await?;
+        }
+        Ok(())
+    }
+}
+
+/// A session borrowed from a [`WebDriverPool`].
+///
+/// This derefs to [`WebDriver`], and returns the session to the pool when dropped.
+#[derive(Debug)]
+pub struct PooledWebDriver {
+    driver: Option<WebDriver>,
+    pool: Arc<PoolInner>,
+    permit: Option<OwnedSemaphorePermit>,
+}
+
+impl PooledWebDriver {
+    /// End this session rather than returning it to the pool.
+    ///
+    /// Use this if the session is broken, for example if the browser crashed.
+    /// A new session will be created for the next `acquire()`.
+    pub async fn discard(mut self) -> WebDriverResult<()> {
+        let _permit = self.permit.take();
+        match self.driver.take() {
+            Some(driver) => driver.quit().await,
+            None => Ok(()),
+        }
+    }
+}
+
+impl Deref for PooledWebDriver {
+    type Target = WebDriver;
+
+    fn deref(&self) -> &Self::Target {
+        self.driver.as_ref().expect("the session has been returned to the pool")
+    }
+}
+
+impl Drop for PooledWebDriver {
+    fn drop(&mut self) {
+        let Some(driver) = self.driver.take() else {
+            return;
+        };
+        let permit = self.permit.take();
+        if self.pool.policy == ReusePolicy::Reuse && !self.pool.semaphore.is_closed() {
+            self.pool.idle.lock().push(driver);
+            return;
+        }
+
+        // Hold the permit until the session has ended, so the pool never has more than
+        // `max_sessions` sessions open.
+        match tokio::runtime::Handle::try_current() {
+            Ok(handle) => {
+                handle.spawn(async move {
+                    if let Err(e) = driver.quit().await {
+                        log::warn!("failed to end pooled WebDriver session: {e}");
+                    }
+                    drop(permit);
+                });
+            }
+            Err(_) => log::warn!("no tokio runtime available to end pooled WebDriver session"),
+        }
+    }
+}
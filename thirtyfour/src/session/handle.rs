@@ -1,30 +1,56 @@
 use crate::action_chain::ActionChain;
+use crate::common::command::Command;
 use crate::common::config::WebDriverConfig;
-use crate::error::WebDriverResult;
+use crate::common::logging::{request_command, trace_body, LOG_TARGET};
+use crate::error::{is_connection_closed, WebDriverError, WebDriverErrorDetails, WebDriverResult};
+use crate::extensions::query::{ElementPoller, ElementPollerWithTimeout};
+use crate::js::{
+    define_atom_script, invoke_atom_script, wrap_promise_script, FIND_RELATIVE_ELEMENTS,
+};
 use crate::session::scriptret::ScriptRet;
+use crate::upstream::{CmdError, WebDriverCompatibleCommand};
 use crate::Cookie;
 use crate::Form;
-use crate::{By, Rect, SessionId, SwitchTo, WebElement};
-use crate::{TimeoutConfiguration, WebDriverStatus, WindowHandle};
-use serde_json::Value;
+use crate::{
+    By, LogEntry, LogType, OptionRect, PrintParameters, Rect, RelativeBy, SessionId, SwitchTo,
+    WebElement,
+};
+use crate::{
+    Capabilities, TimeoutConfiguration, TimeoutConfigurationHelper, WebDriverStatus, WindowHandle,
+};
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use parking_lot::Mutex;
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use std::collections::HashSet;
 use std::fmt::{Debug, Formatter};
 use std::future::Future;
+use std::io::{Cursor, Write};
 use std::path::Path;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::fs::File;
 use tokio::io::AsyncWriteExt;
+use zip::write::FileOptions;
+use zip::ZipWriter;
 
 /// The SessionHandle contains a shared reference to the [`fantoccini::Client`]
 /// to allow sending commands to the underlying WebDriver.
 #[derive(Clone)]
 pub struct SessionHandle {
-    /// 
+    /// The underlying fantoccini client.
     pub client: fantoccini::Client,
     /// The session id for this webdriver session.
     pub session_id: SessionId,
     /// The config used by this instance.
     pub config: WebDriverConfig,
+    /// Whether the server url contains credentials (HTTP Basic Auth).
+    pub(crate) server_credentials: bool,
+    /// The names of the atoms that have been defined on the page by this session.
+    ///
+    /// See `SessionHandle::execute_atom()`.
+    pub(crate) atoms: Arc<Mutex<HashSet<&'static str>>>,
 }
 
 impl Debug for SessionHandle {
@@ -43,6 +69,8 @@ impl SessionHandle {
             client,
             session_id,
             config: WebDriverConfig::default(),
+            server_credentials: false,
+            atoms: Arc::default(),
         })
     }
 
@@ -51,11 +79,14 @@ impl SessionHandle {
         client: fantoccini::Client,
         session_id: SessionId,
         config: WebDriverConfig,
+        server_credentials: bool,
     ) -> WebDriverResult<Self> {
         Ok(Self {
             client,
             session_id,
             config,
+            server_credentials,
+            atoms: Arc::default(),
         })
     }
 
@@ -70,6 +101,8 @@ impl SessionHandle {
             client: self.client.clone(),
             session_id: self.session_id.clone(),
             config,
+            server_credentials: self.server_credentials,
+            atoms: self.atoms.clone(),
         })
     }
 
@@ -82,10 +115,58 @@ impl SessionHandle {
     }
 
     /// The session id for this webdriver session.
+    ///
+    /// Use `session_id().as_str()` to get the id as a string slice, e.g. for logging.
     pub fn session_id(&self) -> &SessionId {
         &self.session_id
     }
 
+    /// The capabilities returned by the WebDriver when the session was created.
+    ///
+    /// These describe the actual browser session, and can differ from the
+    /// capabilities that were requested.
+    pub fn capabilities(&self) -> Option<&Capabilities> {
+        self.client.capabilities()
+    }
+
+    /// Get the specified string capability returned by the WebDriver.
+    fn capability_str(&self, name: &str) -> Option<String> {
+        self.capabilities()?.get(name)?.as_str().map(String::from)
+    }
+
+    /// The name of the browser for this session (e.g. "firefox" or "chrome").
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// if driver.browser_name().as_deref() == Some("firefox") {
+    ///     println!("running firefox {:?}", driver.browser_version());
+    /// }
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub fn browser_name(&self) -> Option<String> {
+        self.capability_str("browserName")
+    }
+
+    /// The version of the browser for this session.
+    pub fn browser_version(&self) -> Option<String> {
+        self.capability_str("browserVersion")
+    }
+
+    /// The name of the platform the browser is running on (e.g. "linux" or "windows").
+    pub fn platform_name(&self) -> Option<String> {
+        self.capability_str("platformName")
+    }
+
     /// The configuration used by this instance.
     ///
     /// NOTE: It's sometimes useful to have separate instances pointing at the same
@@ -115,7 +196,7 @@ impl SessionHandle {
     /// # }
     /// ```
     pub async fn status(&self) -> WebDriverResult<WebDriverStatus> {
-        Ok(self.client.status().await?)
+        self.retry_on_closed_connection("status", "GET status", || self.client.status()).await
     }
 
     /// Close the current window or tab. This will close the session if no other windows exist.
@@ -147,14 +228,14 @@ impl SessionHandle {
     /// # }
     /// ```
     pub async fn close_window(&self) -> WebDriverResult<()> {
-        self.client.close_window().await?;
+        self.logged("close_window", "DELETE window", self.client.close_window()).await?;
         Ok(())
     }
 
     /// Close the current window or tab. This will close the session if no other windows exist.
     #[deprecated(since = "0.30.0", note = "This method has been renamed to close_window()")]
     pub async fn close(&self) -> WebDriverResult<()> {
-        self.client.close_window().await?;
+        self.logged("close_window", "DELETE window", self.client.close_window()).await?;
         Ok(())
     }
 
@@ -179,7 +260,7 @@ impl SessionHandle {
     where
         S: AsRef<str>,
     {
-        Ok(self.client.goto(url.as_ref()).await?)
+        Ok(self.logged("goto", "POST url", self.client.goto(url.as_ref())).await?)
     }
 
     /// Navigate to the specified URL.
@@ -193,12 +274,13 @@ impl SessionHandle {
 
     /// Get the current URL.
     pub async fn current_url(&self) -> WebDriverResult<url::Url> {
-        Ok(self.client.current_url().await?)
+        self.retry_on_closed_connection("current_url", "GET url", || self.client.current_url())
+            .await
     }
 
     /// Get the page source as a String.
     pub async fn source(&self) -> WebDriverResult<String> {
-        Ok(self.client.source().await?)
+        self.retry_on_closed_connection("source", "GET source", || self.client.source()).await
     }
 
     /// Get the page source as a String.
@@ -209,7 +291,7 @@ impl SessionHandle {
 
     /// Get the page title as a String.
     pub async fn title(&self) -> WebDriverResult<String> {
-        Ok(self.client.title().await?)
+        self.retry_on_closed_connection("title", "GET title", || self.client.title()).await
     }
 
     /// Search for an element on the current page using the specified selector.
@@ -237,7 +319,9 @@ impl SessionHandle {
     /// ```
     pub async fn find(self: &Arc<SessionHandle>, by: impl Into<By>) -> WebDriverResult<WebElement> {
         let by = by.into();
-        let elem = self.client.find(by.locator()).await?;
+        let elem = self
+            .retry_on_closed_connection("find", "POST element", || self.client.find(by.locator()))
+            .await?;
         Ok(self.wrap_element(elem))
     }
 
@@ -247,6 +331,40 @@ impl SessionHandle {
         self.find(by).await
     }
 
+    /// Search for an element on the current page using the specified selector, returning
+    /// `None` if no element matches.
+    ///
+    /// Unlike [`SessionHandle::find`], this does not return an error when the element is
+    /// not found. Any other error is still returned.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// if let Some(elem_banner) = driver.try_find(By::Id("cookie-banner")).await? {
+    ///     elem_banner.find(By::Tag("button")).await?.click().await?;
+    /// }
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn try_find(
+        self: &Arc<SessionHandle>,
+        by: impl Into<By>,
+    ) -> WebDriverResult<Option<WebElement>> {
+        match self.find(by).await {
+            Ok(elem) => Ok(Some(elem)),
+            Err(WebDriverError::NoSuchElement(_)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
     /// Search for all elements on the current page that match the specified selector.
     ///
     /// **NOTE**: For more powerful element queries including polling and filters, see the
@@ -277,10 +395,85 @@ impl SessionHandle {
         by: impl Into<By>,
     ) -> WebDriverResult<Vec<WebElement>> {
         let by = by.into();
-        let elems = self.client.find_all(by.locator()).await?;
+        let elems = self
+            .retry_on_closed_connection("find_all", "POST elements", || {
+                self.client.find_all(by.locator())
+            })
+            .await?;
         Ok(elems.into_iter().map(|x| self.wrap_element(x)).collect())
     }
 
+    /// Search for the first element on the current page that matches the specified
+    /// relative locator.
+    ///
+    /// Returns the element closest to the first anchor element.
+    ///
+    /// The locator is evaluated by a JavaScript helper. It is only sent in full the first
+    /// time it is needed on a page, and later calls invoke it by name.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// use thirtyfour::RelativeBy;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// let label = driver.find(By::Id("username-label")).await?;
+    /// let input = driver.find_relative(RelativeBy::with(By::Tag("input")).near(&label)).await?;
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn find_relative(
+        self: &Arc<SessionHandle>,
+        by: RelativeBy,
+    ) -> WebDriverResult<WebElement> {
+        let elems = self.find_all_relative(by.clone()).await?;
+        elems.into_iter().next().ok_or_else(|| {
+            WebDriverError::NoSuchElement(WebDriverErrorDetails::new(format!(
+                "no element found matching {by}"
+            )))
+        })
+    }
+
+    /// Search for all elements on the current page that match the specified relative locator.
+    ///
+    /// Elements are sorted by proximity to the first anchor element.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// use thirtyfour::RelativeBy;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// let header = driver.find(By::Id("header")).await?;
+    /// let footer = driver.find(By::Id("footer")).await?;
+    /// let elems = driver
+    ///     .find_all_relative(RelativeBy::with(By::Tag("button")).below(&header).above(&footer))
+    ///     .await?;
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn find_all_relative(
+        self: &Arc<SessionHandle>,
+        by: RelativeBy,
+    ) -> WebDriverResult<Vec<WebElement>> {
+        let ret = self
+            .execute_atom("findRelativeElements", FIND_RELATIVE_ELEMENTS, vec![by.to_json()?])
+            .await?;
+        ret.elements()
+    }
+
     /// Search for all elements on the current page that match the specified selector.
     #[deprecated(since = "0.30.0", note = "This method has been renamed to find_all()")]
     pub async fn find_elements(
@@ -295,7 +488,7 @@ impl SessionHandle {
     /// Through the returned `Form`, HTML forms can be filled out and submitted.
     pub async fn form(&self, by: impl Into<By>) -> WebDriverResult<Form> {
         let by = by.into();
-        let form = self.client.form(by.locator()).await?;
+        let form = self.logged("form", "POST element", self.client.form(by.locator())).await?;
         Ok(form)
     }
 
@@ -347,12 +540,18 @@ impl SessionHandle {
     /// #     })
     /// # }
     /// ```
+    ///
+    /// Alternatively, use [`ScriptArgs`] to build the arguments.
+    ///
+    /// [`ScriptArgs`]: crate::session::scriptargs::ScriptArgs
     pub async fn execute(
         self: &Arc<SessionHandle>,
         script: &str,
         args: Vec<Value>,
     ) -> WebDriverResult<ScriptRet> {
-        let v = self.client.execute(script, args).await?;
+        trace_body("POST execute/sync", || json!({ "script": script, "args": args }));
+        let v =
+            self.logged("execute", "POST execute/sync", self.client.execute(script, args)).await?;
         Ok(ScriptRet::new(self.clone(), v))
     }
 
@@ -430,10 +629,102 @@ impl SessionHandle {
         script: &str,
         args: Vec<Value>,
     ) -> WebDriverResult<ScriptRet> {
-        let v = self.client.execute_async(script, args).await?;
+        trace_body("POST execute/async", || json!({ "script": script, "args": args }));
+        let v = self
+            .logged("execute_async", "POST execute/async", self.client.execute_async(script, args))
+            .await?;
         Ok(ScriptRet::new(self.clone(), v))
     }
 
+    /// Execute the specified Javascript, wait for the promise it returns to resolve, and
+    /// deserialize the resolved value.
+    ///
+    /// Unlike [`SessionHandle::execute_async()`], the script does not need to call a
+    /// callback. It runs inside an async function, so it can simply `return await` a
+    /// promise. The arguments are available via `arguments` as usual.
+    ///
+    /// Returns a `WebDriverError::JavascriptError` if the promise is rejected.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// let status: u16 = driver.execute_promise_script(r#"
+    ///     const response = await fetch(arguments[0]);
+    ///     return response.status;
+    ///     "#, vec![serde_json::to_value("/index.html")?]
+    /// ).await?;
+    /// assert_eq!(status, 200);
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn execute_promise_script<T>(
+        self: &Arc<SessionHandle>,
+        script: &str,
+        args: Vec<Value>,
+    ) -> WebDriverResult<T>
+    where
+        T: DeserializeOwned,
+    {
+        let mut ret = self
+            .logged(
+                "execute_promise_script",
+                "POST execute/async",
+                self.client.execute_async(&wrap_promise_script(script), args),
+            )
+            .await?;
+        match ret["error"].as_str() {
+            Some(error) => Err(WebDriverError::JavascriptError(WebDriverErrorDetails::new(
+                format!("the promise was rejected: {error}"),
+            ))),
+            None => Ok(serde_json::from_value(ret["value"].take())?),
+        }
+    }
+
+    /// Execute one of the JavaScript helpers (atoms) used by `thirtyfour`.
+    ///
+    /// The first time an atom is used in this session, the whole script is sent, and it is
+    /// defined as a function on `window`. After that, only a short script that calls the
+    /// function by name is sent. If the atom is no longer defined, for example after
+    /// navigating to another page, the whole script is sent again.
+    pub(crate) async fn execute_atom(
+        self: &Arc<SessionHandle>,
+        name: &'static str,
+        script: &str,
+        args: Vec<Value>,
+    ) -> WebDriverResult<ScriptRet> {
+        let defined = self.atoms.lock().contains(name);
+        if defined {
+            let mut ret = self
+                .logged(
+                    "execute_atom",
+                    "POST execute/sync",
+                    self.client.execute(&invoke_atom_script(name), args.clone()),
+                )
+                .await?;
+            if ret["missing"] != Value::Bool(true) {
+                return Ok(ScriptRet::new(self.clone(), ret["value"].take()));
+            }
+        }
+
+        let mut ret = self
+            .logged(
+                "execute_atom",
+                "POST execute/sync",
+                self.client.execute(&define_atom_script(name, script), args),
+            )
+            .await?;
+        self.atoms.lock().insert(name);
+        Ok(ScriptRet::new(self.clone(), ret["value"].take()))
+    }
+
     /// Execute the specified Javascrypt asynchronously and return the result.
     #[deprecated(since = "0.30.0", note = "This method has been renamed to execute_async()")]
     pub async fn execute_script_async(
@@ -478,7 +769,7 @@ impl SessionHandle {
     /// # }
     /// ```
     pub async fn window(&self) -> WebDriverResult<WindowHandle> {
-        Ok(self.client.window().await?)
+        self.retry_on_closed_connection("window", "GET window", || self.client.window()).await
     }
 
     /// Get the current window handle.
@@ -512,7 +803,8 @@ impl SessionHandle {
     /// # }
     /// ```
     pub async fn windows(&self) -> WebDriverResult<Vec<WindowHandle>> {
-        Ok(self.client.windows().await?)
+        self.retry_on_closed_connection("windows", "GET window/handles", || self.client.windows())
+            .await
     }
 
     /// Get all window handles for the current session.
@@ -539,7 +831,8 @@ impl SessionHandle {
     /// # }
     /// ```
     pub async fn maximize_window(&self) -> WebDriverResult<()> {
-        self.client.maximize_window().await?;
+        self.logged("maximize_window", "POST window/maximize", self.client.maximize_window())
+            .await?;
         Ok(())
     }
 
@@ -563,7 +856,8 @@ impl SessionHandle {
     /// # }
     /// ```
     pub async fn minimize_window(&self) -> WebDriverResult<()> {
-        self.client.minimize_window().await?;
+        self.logged("minimize_window", "POST window/minimize", self.client.minimize_window())
+            .await?;
         Ok(())
     }
 
@@ -585,14 +879,15 @@ impl SessionHandle {
     /// # }
     /// ```
     pub async fn fullscreen_window(&self) -> WebDriverResult<()> {
-        self.client.fullscreen_window().await?;
+        self.logged("fullscreen_window", "POST window/fullscreen", self.client.fullscreen_window())
+            .await?;
         Ok(())
     }
 
     /// Get the current window rectangle, in pixels.
     ///
     /// The returned Rect struct has members `x`, `y`, `width`, `height`,
-    /// all i32.
+    /// all i64.
     ///
     /// # Example:
     /// ```no_run
@@ -613,8 +908,8 @@ impl SessionHandle {
     /// # }
     /// ```
     pub async fn get_window_rect(&self) -> WebDriverResult<Rect> {
-        let (x, y, w, h) = self.client.get_window_rect().await?;
-        Ok(Rect::new(x as i64, y as i64, w as i64, h as i64))
+        let v = self.cmd(Command::GetWindowRect).await?;
+        Ok(serde_json::from_value(v)?)
     }
 
     /// Set the current window rectangle, in pixels.
@@ -640,7 +935,137 @@ impl SessionHandle {
         width: u32,
         height: u32,
     ) -> WebDriverResult<()> {
-        Ok(self.client.set_window_rect(x, y, width, height).await?)
+        Ok(self
+            .logged(
+                "set_window_rect",
+                "POST window/rect",
+                self.client.set_window_rect(x, y, width, height),
+            )
+            .await?)
+    }
+
+    /// Update the current window rectangle, leaving any omitted fields unchanged, and
+    /// return the new rectangle.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// use thirtyfour::OptionRect;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// // Resize the window without moving it.
+    /// driver.update_window_rect(OptionRect::new().with_size(1024, 768)).await?;
+    ///
+    /// // Restore the original rectangle later.
+    /// let original = driver.get_window_rect().await?;
+    /// driver.update_window_rect(original).await?;
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn update_window_rect(&self, rect: impl Into<OptionRect>) -> WebDriverResult<Rect> {
+        let v = self.cmd(Command::SetWindowRect(rect.into())).await?;
+        Ok(serde_json::from_value(v)?)
+    }
+
+    /// Get the current window size as `(width, height)`, in pixels.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// driver.set_window_size(800, 600).await?;
+    /// let (width, height) = driver.window_size().await?;
+    /// assert_eq!((width, height), (800, 600));
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn window_size(&self) -> WebDriverResult<(i64, i64)> {
+        let rect = self.get_window_rect().await?;
+        Ok((rect.width, rect.height))
+    }
+
+    /// Set the current window size, in pixels, without changing its position.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// driver.set_window_size(800, 600).await?;
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn set_window_size(&self, width: i64, height: i64) -> WebDriverResult<()> {
+        self.update_window_rect(OptionRect::new().with_size(width, height)).await?;
+        Ok(())
+    }
+
+    /// Get the current window position as `(x, y)`, in pixels.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// driver.set_window_position(10, 20).await?;
+    /// let (x, y) = driver.window_position().await?;
+    /// assert_eq!((x, y), (10, 20));
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn window_position(&self) -> WebDriverResult<(i64, i64)> {
+        let rect = self.get_window_rect().await?;
+        Ok((rect.x, rect.y))
+    }
+
+    /// Set the current window position, in pixels, without changing its size.
+    ///
+    /// The coordinates may be negative, e.g. to place the window on a monitor
+    /// to the left of or above the primary one.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// driver.set_window_position(10, 20).await?;
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn set_window_position(&self, x: i64, y: i64) -> WebDriverResult<()> {
+        self.update_window_rect(OptionRect::new().with_pos(x, y)).await?;
+        Ok(())
     }
 
     /// Go back. This is equivalent to clicking the browser's back button.
@@ -661,7 +1086,7 @@ impl SessionHandle {
     /// # }
     /// ```
     pub async fn back(&self) -> WebDriverResult<()> {
-        Ok(self.client.back().await?)
+        Ok(self.logged("back", "POST back", self.client.back()).await?)
     }
 
     /// Go forward. This is equivalent to clicking the browser's forward button.
@@ -682,7 +1107,7 @@ impl SessionHandle {
     /// # }
     /// ```
     pub async fn forward(&self) -> WebDriverResult<()> {
-        self.client.forward().await?;
+        self.logged("forward", "POST forward", self.client.forward()).await?;
         Ok(())
     }
 
@@ -704,11 +1129,63 @@ impl SessionHandle {
     /// # }
     /// ```
     pub async fn refresh(&self) -> WebDriverResult<()> {
-        Ok(self.client.refresh().await?)
+        Ok(self.logged("refresh", "POST refresh", self.client.refresh()).await?)
+    }
+
+    /// Get the `document.readyState` of the current page.
+    ///
+    /// This will be one of `loading`, `interactive` or `complete`.
+    pub async fn document_ready_state(&self) -> WebDriverResult<String> {
+        let script = "return document.readyState;";
+        let v = self
+            .logged("execute", "POST execute/sync", self.client.execute(script, Vec::new()))
+            .await?;
+        Ok(serde_json::from_value(v)?)
+    }
+
+    /// Wait until the `document.readyState` of the current page is `complete`.
+    ///
+    /// Navigation returns once the page load strategy is satisfied, which may be before
+    /// the page has finished loading. This polls the ready state until the page is
+    /// complete, and returns a `Timeout` error if it is not complete within the
+    /// specified duration.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// use std::time::Duration;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// driver.goto("https://www.rust-lang.org/").await?;
+    /// driver.wait_for_document_ready(Duration::from_secs(10)).await?;
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn wait_for_document_ready(&self, timeout: Duration) -> WebDriverResult<()> {
+        let mut poller = ElementPollerWithTimeout::new(timeout, Duration::from_millis(100));
+        loop {
+            if self.document_ready_state().await? == "complete" {
+                return Ok(());
+            }
+            if !poller.tick().await {
+                return Err(WebDriverError::Timeout(WebDriverErrorDetails::new(format!(
+                    "document was not ready after {timeout:?}"
+                ))));
+            }
+        }
     }
 
     /// Get all timeouts for the current session.
     ///
+    /// This returns the script, page load and implicit wait timeouts currently
+    /// set by the WebDriver.
+    ///
     /// # Example:
     /// ```no_run
     /// # use thirtyfour::prelude::*;
@@ -722,13 +1199,22 @@ impl SessionHandle {
     /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
     /// let timeouts = driver.get_timeouts().await?;
     /// println!("Page load timeout = {:?}", timeouts.page_load());
+    ///
+    /// // Temporarily change the timeouts, and restore them afterwards.
+    /// driver.set_page_load_timeout(Duration::from_secs(5)).await?;
+    /// driver.goto("https://www.rust-lang.org/").await?;
+    /// driver.update_timeouts(timeouts).await?;
     /// #         driver.quit().await?;
     /// #         Ok(())
     /// #     })
     /// # }
     /// ```
     pub async fn get_timeouts(&self) -> WebDriverResult<TimeoutConfiguration> {
-        let timeouts = self.client.get_timeouts().await?;
+        let timeouts = self
+            .retry_on_closed_connection("get_timeouts", "GET timeouts", || {
+                self.client.get_timeouts()
+            })
+            .await?;
         Ok(timeouts)
     }
 
@@ -753,8 +1239,8 @@ impl SessionHandle {
     /// #     block_on(async {
     /// #         let caps = DesiredCapabilities::chrome();
     /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
-    /// // Setting timeouts to None means those timeout values will not be updated.
-    /// let timeouts = TimeoutConfiguration::new(None, Some(Duration::new(11, 0)), None);
+    /// // Timeouts that are not set will not be updated.
+    /// let timeouts = TimeoutConfiguration::builder().page_load(Duration::new(11, 0)).build();
     /// driver.update_timeouts(timeouts).await?;
     /// #         driver.quit().await?;
     /// #         Ok(())
@@ -762,7 +1248,8 @@ impl SessionHandle {
     /// # }
     /// ```
     pub async fn update_timeouts(&self, timeouts: TimeoutConfiguration) -> WebDriverResult<()> {
-        self.client.update_timeouts(timeouts).await?;
+        self.logged("update_timeouts", "POST timeouts", self.client.update_timeouts(timeouts))
+            .await?;
         Ok(())
     }
 
@@ -804,7 +1291,7 @@ impl SessionHandle {
     /// # }
     /// ```
     pub async fn set_implicit_wait_timeout(&self, time_to_wait: Duration) -> WebDriverResult<()> {
-        let timeouts = TimeoutConfiguration::new(None, None, Some(time_to_wait));
+        let timeouts = TimeoutConfiguration::builder().implicit(time_to_wait).build();
         self.update_timeouts(timeouts).await
     }
 
@@ -832,7 +1319,7 @@ impl SessionHandle {
     /// # }
     /// ```
     pub async fn set_script_timeout(&self, time_to_wait: Duration) -> WebDriverResult<()> {
-        let timeouts = TimeoutConfiguration::new(Some(time_to_wait), None, None);
+        let timeouts = TimeoutConfiguration::builder().script(time_to_wait).build();
         self.update_timeouts(timeouts).await
     }
 
@@ -860,7 +1347,7 @@ impl SessionHandle {
     /// # }
     /// ```
     pub async fn set_page_load_timeout(&self, time_to_wait: Duration) -> WebDriverResult<()> {
-        let timeouts = TimeoutConfiguration::new(None, Some(time_to_wait), None);
+        let timeouts = TimeoutConfiguration::builder().page_load(time_to_wait).build();
         self.update_timeouts(timeouts).await
     }
 
@@ -897,6 +1384,36 @@ impl SessionHandle {
         ActionChain::new(self.clone())
     }
 
+    /// Send the specified keystrokes to the element that currently has focus.
+    ///
+    /// This uses the Actions API, so the keys go to whichever element is active rather
+    /// than a specific element. Modifier keys are held until the end of the text or
+    /// until `Key::Null` is sent. See [`ActionChain::send_keys_with_modifiers()`] for details.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// // Select all text in the focused element, then replace it.
+    /// driver.send_keys(Key::Control + "a").await?;
+    /// driver.send_keys("thirtyfour").await?;
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn send_keys(
+        self: &Arc<SessionHandle>,
+        keys: impl AsRef<str>,
+    ) -> WebDriverResult<()> {
+        self.action_chain().send_keys_with_modifiers(keys).perform().await
+    }
+
     /// Create a new Actions chain.
     ///
     /// Also see [`WebDriver::action_chain`] for a builder-based alternative.
@@ -927,7 +1444,36 @@ impl SessionHandle {
         &self,
         actions: impl Into<crate::actions::Actions>,
     ) -> WebDriverResult<()> {
-        self.client.perform_actions(actions).await?;
+        self.logged("perform_actions", "POST actions", self.client.perform_actions(actions))
+            .await?;
+        Ok(())
+    }
+
+    /// Release all keys and mouse buttons that are currently held down, and reset the
+    /// state of all input sources.
+    ///
+    /// This is useful for cleaning up after an action sequence that did not release
+    /// everything it pressed, for example a failed drag and drop.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// let elem = driver.find(By::Id("button1")).await?;
+    /// driver.action_chain().click_and_hold_element(&elem).perform().await?;
+    /// driver.release_actions().await?;
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn release_actions(&self) -> WebDriverResult<()> {
+        self.logged("release_actions", "DELETE actions", self.client.release_actions()).await?;
         Ok(())
     }
 
@@ -953,7 +1499,10 @@ impl SessionHandle {
     /// # }
     /// ```
     pub async fn get_all_cookies(&self) -> WebDriverResult<Vec<Cookie<'static>>> {
-        Ok(self.client.get_all_cookies().await?)
+        self.retry_on_closed_connection("get_all_cookies", "GET cookie", || {
+            self.client.get_all_cookies()
+        })
+        .await
     }
 
     /// Get all cookies.
@@ -982,7 +1531,10 @@ impl SessionHandle {
     /// # }
     /// ```
     pub async fn get_named_cookie(&self, name: &str) -> WebDriverResult<Cookie<'static>> {
-        Ok(self.client.get_named_cookie(name).await?)
+        self.retry_on_closed_connection("get_named_cookie", "GET cookie/{name}", || {
+            self.client.get_named_cookie(name)
+        })
+        .await
     }
 
     /// Get the specified cookie.
@@ -991,6 +1543,36 @@ impl SessionHandle {
         self.get_named_cookie(name).await
     }
 
+    /// Get the specified cookie, returning `None` if there is no cookie with that name.
+    ///
+    /// Unlike [`SessionHandle::get_named_cookie`], this does not return an error when the
+    /// cookie does not exist. Any other error is still returned.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// if driver.get_cookie_opt("session").await?.is_none() {
+    ///     driver.find(By::Id("login")).await?.click().await?;
+    /// }
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn get_cookie_opt(&self, name: &str) -> WebDriverResult<Option<Cookie<'static>>> {
+        match self.get_named_cookie(name).await {
+            Ok(cookie) => Ok(Some(cookie)),
+            Err(WebDriverError::NoSuchCookie(_)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
     /// Delete the specified cookie.
     ///
     /// # Example:
@@ -1010,7 +1592,9 @@ impl SessionHandle {
     /// # }
     /// ```
     pub async fn delete_cookie(&self, name: &str) -> WebDriverResult<()> {
-        Ok(self.client.delete_cookie(name).await?)
+        Ok(self
+            .logged("delete_cookie", "DELETE cookie/{name}", self.client.delete_cookie(name))
+            .await?)
     }
 
     /// Delete all cookies.
@@ -1032,7 +1616,9 @@ impl SessionHandle {
     /// # }
     /// ```
     pub async fn delete_all_cookies(&self) -> WebDriverResult<()> {
-        Ok(self.client.delete_all_cookies().await?)
+        Ok(self
+            .logged("delete_all_cookies", "DELETE cookie", self.client.delete_all_cookies())
+            .await?)
     }
 
     /// Add the specified cookie.
@@ -1048,24 +1634,29 @@ impl SessionHandle {
     /// #         let caps = DesiredCapabilities::chrome();
     /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
     /// driver.goto("https://wikipedia.org").await?;
-    /// let mut cookie = Cookie::new("key", "value");
-    /// cookie.set_domain("wikipedia.org");
-    /// cookie.set_path("/");
-    /// cookie.set_same_site(Some(SameSite::Lax));
-    /// driver.add_cookie(cookie.clone()).await?;
+    /// let cookie = Cookie::new("key", "value")
+    ///     .with_domain("wikipedia.org")
+    ///     .with_path("/")
+    ///     .with_same_site(SameSite::Lax);
+    /// driver.add_cookie(cookie).await?;
     /// #         driver.quit().await?;
     /// #         Ok(())
     /// #     })
     /// # }
     /// ```
     pub async fn add_cookie(&self, cookie: Cookie<'static>) -> WebDriverResult<()> {
-        self.client.add_cookie(cookie).await?;
+        trace_body(
+            "POST cookie",
+            || json!({ "cookie": { "name": cookie.name(), "value": cookie.value() } }),
+        );
+        self.logged("add_cookie", "POST cookie", self.client.add_cookie(cookie)).await?;
         Ok(())
     }
 
     /// Take a screenshot of the current window and return it as PNG bytes.
     pub async fn screenshot_as_png(&self) -> WebDriverResult<Vec<u8>> {
-        Ok(self.client.screenshot().await?)
+        self.retry_on_closed_connection("screenshot", "GET screenshot", || self.client.screenshot())
+            .await
     }
 
     /// Take a screenshot of the current window and write it to the specified filename.
@@ -1076,6 +1667,290 @@ impl SessionHandle {
         Ok(())
     }
 
+    /// Get the page source and write it to the specified filename.
+    ///
+    /// Together with [`SessionHandle::screenshot()`], this is useful for saving the state
+    /// of the page when a test fails.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// use std::path::Path;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// driver.goto("https://www.rust-lang.org/").await?;
+    /// driver.save_page_source(Path::new("failure.html")).await?;
+    /// driver.screenshot(Path::new("failure.png")).await?;
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn save_page_source(&self, path: &Path) -> WebDriverResult<()> {
+        let source = self.source().await?;
+        let mut file = File::create(path).await?;
+        file.write_all(source.as_bytes()).await?;
+        file.flush().await?;
+        Ok(())
+    }
+
+    /// Print the current page to PDF and return the PDF document as bytes.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// use thirtyfour::PrintParameters;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// driver.goto("https://www.rust-lang.org").await?;
+    /// let pdf = driver.print_page(PrintParameters::default()).await?;
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn print_page(&self, params: PrintParameters) -> WebDriverResult<Vec<u8>> {
+        let src = self.cmd(Command::Print(params)).await?;
+        if let Some(src) = src.as_str() {
+            let decoded = BASE64_STANDARD.decode(src)?;
+            Ok(decoded)
+        } else {
+            Err(WebDriverError::Cmd(CmdError::NotW3C(src)))
+        }
+    }
+
+    /// Print the current page to PDF and write it to the specified filename.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// use std::path::Path;
+    /// use thirtyfour::PrintParameters;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// driver.goto("https://www.rust-lang.org").await?;
+    /// driver.print_page_to_file(PrintParameters::default(), Path::new("page.pdf")).await?;
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn print_page_to_file(
+        &self,
+        params: PrintParameters,
+        path: &Path,
+    ) -> WebDriverResult<()> {
+        let pdf = self.print_page(params).await?;
+        let mut file = File::create(path).await?;
+        file.write_all(&pdf).await?;
+        file.flush().await?;
+        Ok(())
+    }
+
+    /// Upload the specified local file to the remote WebDriver, and return the path
+    /// of the uploaded file on the remote machine.
+    ///
+    /// This is only needed when the WebDriver is running on a different machine,
+    /// such as a Selenium Grid. The returned path can then be sent to a file input
+    /// element using `WebElement::send_keys()`. For a local WebDriver, use
+    /// `WebElement::send_file()` instead.
+    ///
+    /// NOTE: This uses a Selenium-specific endpoint and is not supported by
+    /// chromedriver or geckodriver directly.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// use std::path::Path;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// let remote_path = driver.upload_file(Path::new("/tmp/report.pdf")).await?;
+    /// let elem = driver.find(By::Css("input[type='file']")).await?;
+    /// elem.send_keys(remote_path).await?;
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn upload_file(&self, path: &Path) -> WebDriverResult<String> {
+        let file_name = path.file_name().and_then(|x| x.to_str()).ok_or_else(|| {
+            WebDriverError::InvalidArgument(WebDriverErrorDetails::new(format!(
+                "invalid file path: {}",
+                path.display()
+            )))
+        })?;
+        let contents = tokio::fs::read(path).await?;
+        let zipped = zip_file(file_name, &contents).map_err(|e| {
+            WebDriverError::CustomError(format!("failed to zip file for upload: {e}"))
+        })?;
+        let value = self.cmd(Command::UploadFile(BASE64_STANDARD.encode(zipped))).await?;
+        match value.as_str() {
+            Some(remote_path) => Ok(remote_path.to_string()),
+            None => Err(WebDriverError::Cmd(CmdError::NotW3C(value))),
+        }
+    }
+
+    /// Get the entries of the specified log since the last time it was retrieved.
+    ///
+    /// This is useful for checking for javascript console errors using
+    /// `LogType::Browser`. Logs are not part of the W3C WebDriver spec, so they are only
+    /// supported by some drivers (e.g. chromedriver and Selenium). The available
+    /// log types can be found using `get_log_types()`.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// use thirtyfour::LogType;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// driver.goto("https://www.rust-lang.org/").await?;
+    /// for entry in driver.get_log(LogType::Browser).await? {
+    ///     println!("[{}] {}", entry.level, entry.message);
+    /// }
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn get_log(&self, log_type: LogType) -> WebDriverResult<Vec<LogEntry>> {
+        let value = self
+            .issue_cmd_with_fallback(
+                Command::GetLog(log_type.clone()),
+                Command::GetLegacyLog(log_type),
+            )
+            .await?;
+        Ok(serde_json::from_value(value)?)
+    }
+
+    /// Get the log types that are available for `get_log()`.
+    pub async fn get_log_types(&self) -> WebDriverResult<Vec<LogType>> {
+        let value =
+            self.issue_cmd_with_fallback(Command::GetLogTypes, Command::GetLegacyLogTypes).await?;
+        Ok(serde_json::from_value(value)?)
+    }
+
+    /// Issue the specified command, and issue the legacy command instead if the
+    /// WebDriver does not support it.
+    async fn issue_cmd_with_fallback(
+        &self,
+        cmd: Command,
+        legacy_cmd: Command,
+    ) -> WebDriverResult<Value> {
+        match self.cmd(cmd).await {
+            Err(
+                WebDriverError::UnknownCommand(_)
+                | WebDriverError::UnknownMethod(_)
+                | WebDriverError::UnknownPath(_),
+            ) => self.cmd(legacy_cmd).await,
+            result => result,
+        }
+    }
+
+    /// Run the specified read-only command, and run it again if the connection to the
+    /// WebDriver server was closed while the command was in flight.
+    ///
+    /// Idle proxies in front of remote grids may drop keep-alive connections. The closed
+    /// connection is discarded, so the retry is sent on a new connection.
+    async fn retry_on_closed_connection<T, F, Fut>(
+        &self,
+        name: &str,
+        endpoint: &str,
+        f: F,
+    ) -> WebDriverResult<T>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T, CmdError>>,
+    {
+        match self.logged(name, endpoint, f()).await {
+            Err(e) if is_connection_closed(&e) => {
+                log::debug!("connection to the WebDriver server was closed ({e}), reconnecting");
+                Ok(self.logged(name, endpoint, f()).await?)
+            }
+            result => Ok(result?),
+        }
+    }
+
+    /// Wait for the specified WebDriver command to complete, and log its name, endpoint and
+    /// the elapsed time at `debug` level to the `thirtyfour::commands` target.
+    ///
+    /// The endpoint is the HTTP method and the command, e.g. `POST url`.
+    pub(crate) async fn logged<T, Fut>(
+        &self,
+        name: &str,
+        endpoint: &str,
+        command: Fut,
+    ) -> Result<T, CmdError>
+    where
+        Fut: Future<Output = Result<T, CmdError>>,
+    {
+        let started = Instant::now();
+        let result = command.await;
+        match &result {
+            Ok(_) => log::debug!(
+                target: LOG_TARGET,
+                "{name} ({endpoint}) completed in {:?}",
+                started.elapsed()
+            ),
+            Err(e) => log::debug!(
+                target: LOG_TARGET,
+                "{name} ({endpoint}) failed in {:?}: {e}",
+                started.elapsed()
+            ),
+        }
+        result
+    }
+
+    /// Send the specified command to the WebDriver server via `SessionHandle::logged()`.
+    ///
+    /// The request body is also logged at `trace` level.
+    pub(crate) async fn cmd<C>(&self, command: C) -> WebDriverResult<Value>
+    where
+        C: WebDriverCompatibleCommand + Debug + Send + 'static,
+    {
+        if !log::log_enabled!(target: LOG_TARGET, log::Level::Debug) {
+            return Ok(self.client.issue_cmd(command).await?);
+        }
+
+        // The name of the enum variant, without its fields.
+        let debug = format!("{command:?}");
+        let name = debug.split(|c: char| !c.is_alphanumeric()).next().unwrap_or_default();
+        let base_url = url::Url::parse("http://localhost/").expect("valid url");
+        let endpoint = match command.endpoint(&base_url, Some(self.session_id.as_str())) {
+            Ok(url) => {
+                let (method, body) = command.method_and_body(&url);
+                let endpoint = format!("{method} {}", request_command(url.path()));
+                if let Some(body) = body {
+                    trace_body(&endpoint, || {
+                        serde_json::from_str(&body).unwrap_or(Value::String(body))
+                    });
+                }
+                endpoint
+            }
+            Err(_) => String::from("?"),
+        };
+        Ok(self.logged(name, &endpoint, self.client.issue_cmd(command)).await?)
+    }
+
     /// Return a SwitchTo struct for switching to another window or frame.
     #[deprecated(
         since = "0.30.0",
@@ -1175,3 +2050,11 @@ impl SessionHandle {
         result
     }
 }
+
+/// Create an in-memory zip archive containing a single file.
+fn zip_file(file_name: &str, contents: &[u8]) -> zip::result::ZipResult<Vec<u8>> {
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    zip.start_file(file_name, FileOptions::default())?;
+    zip.write_all(contents)?;
+    Ok(zip.finish()?.into_inner())
+}
@@ -1,4 +1,9 @@
 /// The underlying session handle.
 pub mod handle;
+/// Screenshot helpers using the `image` crate.
+#[cfg(feature = "image")]
+pub(crate) mod screenshot;
+/// Helper for arguments passed to scripts.
+pub mod scriptargs;
 /// Helper for values returned from scripts.
 pub mod scriptret;
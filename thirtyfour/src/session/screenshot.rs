@@ -0,0 +1,214 @@
+use crate::common::command::Command;
+use crate::error::{WebDriverError, WebDriverResult};
+use crate::session::handle::SessionHandle;
+use crate::upstream::CmdError;
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use image::{DynamicImage, GenericImageView, ImageFormat, RgbaImage};
+use serde::Deserialize;
+use std::io::Cursor;
+use std::sync::Arc;
+
+/// Get the page dimensions and current scroll position, in CSS pixels.
+const GET_PAGE_METRICS: &str = r#"
+    const root = document.documentElement;
+    const body = document.body || root;
+    return {
+        pageHeight: Math.max(root.scrollHeight, body.scrollHeight),
+        viewportHeight: window.innerHeight,
+        scrollX: window.scrollX,
+        scrollY: window.scrollY
+    };
+"#;
+
+/// Scroll to the specified vertical position and return the actual position.
+const SCROLL_TO: &str = r#"
+    window.scrollTo(window.scrollX, arguments[0]);
+    return window.scrollY;
+"#;
+
+/// Hide fixed and sticky elements, so they don't repeat in every part of the screenshot.
+const HIDE_FIXED_ELEMENTS: &str = r#"
+    const hidden = [];
+    for (const elem of document.querySelectorAll('*')) {
+        const position = window.getComputedStyle(elem).position;
+        if (position === 'fixed' || position === 'sticky') {
+            hidden.push([elem, elem.style.getPropertyValue('visibility'),
+                elem.style.getPropertyPriority('visibility')]);
+            elem.style.setProperty('visibility', 'hidden', 'important');
+        }
+    }
+    window.__thirtyfourHiddenElements = hidden;
+"#;
+
+/// Restore the elements hidden by `HIDE_FIXED_ELEMENTS` and the original scroll position.
+const RESTORE_PAGE: &str = r#"
+    for (const [elem, value, priority] of window.__thirtyfourHiddenElements || []) {
+        elem.style.setProperty('visibility', value, priority);
+    }
+    delete window.__thirtyfourHiddenElements;
+    window.scrollTo(arguments[0], arguments[1]);
+"#;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PageMetrics {
+    page_height: f64,
+    viewport_height: f64,
+    scroll_x: f64,
+    scroll_y: f64,
+}
+
+impl SessionHandle {
+    /// Take a screenshot of the current window and return it as an image.
+    ///
+    /// The PNG screenshot is decoded once, so the pixels can be accessed directly
+    /// (e.g. for cropping or comparing screenshots).
+    ///
+    /// Requires the `image` feature.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// driver.goto("https://www.rust-lang.org/").await?;
+    /// let image = driver.screenshot_as_image().await?;
+    /// println!("screenshot is {}x{} pixels", image.width(), image.height());
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn screenshot_as_image(&self) -> WebDriverResult<DynamicImage> {
+        decode_png(&self.screenshot_as_png().await?)
+    }
+
+    /// Take a screenshot of the entire page, not just the visible viewport, and return it as
+    /// PNG bytes.
+    ///
+    /// Firefox supports this natively. For other browsers the page is scrolled one viewport
+    /// at a time, and the screenshots are stitched together. If `hide_fixed_elements` is
+    /// true, fixed and sticky elements (such as headers) are hidden after the first viewport,
+    /// so they only appear once at the top. This modifies the style of those elements while
+    /// the screenshot is taken, and has no effect in Firefox.
+    /// The scroll position and any hidden elements are restored afterwards.
+    ///
+    /// Requires the `image` feature.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// driver.goto("https://www.rust-lang.org/").await?;
+    /// let png = driver.full_page_screenshot_as_png(true).await?;
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn full_page_screenshot_as_png(
+        self: &Arc<SessionHandle>,
+        hide_fixed_elements: bool,
+    ) -> WebDriverResult<Vec<u8>> {
+        match self.cmd(Command::FullPageScreenshot).await {
+            Ok(value) => {
+                let encoded = value.as_str().ok_or_else(|| {
+                    WebDriverError::CustomError(format!("invalid screenshot response: {value}"))
+                })?;
+                return Ok(BASE64_STANDARD.decode(encoded)?);
+            }
+            Err(e) => match e {
+                WebDriverError::UnknownCommand(_)
+                | WebDriverError::UnknownMethod(_)
+                | WebDriverError::UnknownPath(_)
+                | WebDriverError::Cmd(CmdError::NotW3C(_) | CmdError::NotJson(_)) => {}
+                e => return Err(e),
+            },
+        }
+
+        let metrics: PageMetrics = self.execute(GET_PAGE_METRICS, Vec::new()).await?.convert()?;
+        let result = self.stitch_screenshots(&metrics, hide_fixed_elements).await;
+        let restored = self
+            .execute(RESTORE_PAGE, vec![metrics.scroll_x.into(), metrics.scroll_y.into()])
+            .await;
+        // Report the original error if taking the screenshots failed.
+        let image = result?;
+        restored?;
+        encode_png(&image)
+    }
+
+    /// Scroll through the page one viewport at a time and combine the screenshots.
+    async fn stitch_screenshots(
+        self: &Arc<SessionHandle>,
+        metrics: &PageMetrics,
+        hide_fixed_elements: bool,
+    ) -> WebDriverResult<RgbaImage> {
+        if metrics.viewport_height < 1.0 {
+            return Err(WebDriverError::CustomError(
+                "unable to take full page screenshot: the viewport has no height".to_string(),
+            ));
+        }
+
+        let mut stitched: Option<RgbaImage> = None;
+        let mut scale = 1.0;
+        let mut y = 0.0;
+        while y < metrics.page_height {
+            let actual_y: f64 = self.execute(SCROLL_TO, vec![y.into()]).await?.convert()?;
+            let screenshot = decode_png(&self.screenshot_as_png().await?)?;
+            let image = stitched.get_or_insert_with(|| {
+                // Screenshots are in device pixels, which may differ from CSS pixels.
+                scale = f64::from(screenshot.height()) / metrics.viewport_height;
+                let height = (metrics.page_height * scale).round() as u32;
+                RgbaImage::new(screenshot.width(), height)
+            });
+
+            // If the page could not be scrolled all the way to `y` (at the bottom of the
+            // page), the part we need starts further down in the screenshot.
+            let top = ((y - actual_y).max(0.0) * scale).round() as u32;
+            let dest_y = (y * scale).round() as u32;
+            let height =
+                screenshot.height().saturating_sub(top).min(image.height().saturating_sub(dest_y));
+            let width = screenshot.width().min(image.width());
+            if height > 0 {
+                let part = screenshot.view(0, top, width, height).to_image();
+                image::imageops::replace(image, &part, 0, i64::from(dest_y));
+            }
+
+            if y == 0.0 && hide_fixed_elements {
+                self.execute(HIDE_FIXED_ELEMENTS, Vec::new()).await?;
+            }
+            y += metrics.viewport_height;
+        }
+
+        stitched.ok_or_else(|| {
+            WebDriverError::CustomError(
+                "unable to take full page screenshot: the page has no height".to_string(),
+            )
+        })
+    }
+}
+
+/// Decode PNG bytes into an image.
+pub(crate) fn decode_png(png: &[u8]) -> WebDriverResult<DynamicImage> {
+    image::load_from_memory_with_format(png, ImageFormat::Png)
+        .map_err(|e| WebDriverError::DecodeError(format!("unable to decode screenshot: {e}")))
+}
+
+/// Encode an image as PNG bytes.
+fn encode_png(image: &RgbaImage) -> WebDriverResult<Vec<u8>> {
+    let mut png = Vec::new();
+    image
+        .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+        .map_err(|e| WebDriverError::CustomError(format!("unable to encode screenshot: {e}")))?;
+    Ok(png)
+}
@@ -0,0 +1,70 @@
+use crate::error::WebDriverResult;
+use crate::WebElement;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Builder for the arguments passed to a script.
+///
+/// WebElements are passed as element references, which the WebDriver resolves back
+/// into the corresponding DOM nodes before running the script.
+///
+/// # Example:
+/// ```no_run
+/// # use thirtyfour::prelude::*;
+/// # use thirtyfour::support::block_on;
+/// #
+/// # fn main() -> WebDriverResult<()> {
+/// #     block_on(async {
+/// #         let caps = DesiredCapabilities::chrome();
+/// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+/// let elem = driver.find(By::Id("button1")).await?;
+/// let mut args = ScriptArgs::new();
+/// args.push_element(&elem)?.push_value("smooth")?;
+/// driver
+///     .execute(
+///         "arguments[0].scrollIntoView({ behavior: arguments[1] });",
+///         args.into(),
+///     )
+///     .await?;
+/// #         driver.quit().await?;
+/// #         Ok(())
+/// #     })
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ScriptArgs {
+    values: Vec<Value>,
+}
+
+impl ScriptArgs {
+    /// Create a new, empty set of script arguments.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add any Serialize-able value as the next argument.
+    pub fn push_value<T>(&mut self, value: T) -> WebDriverResult<&mut Self>
+    where
+        T: Serialize,
+    {
+        self.values.push(serde_json::to_value(value)?);
+        Ok(self)
+    }
+
+    /// Add a reference to the specified element as the next argument.
+    pub fn push_element(&mut self, element: &WebElement) -> WebDriverResult<&mut Self> {
+        self.values.push(element.to_json()?);
+        Ok(self)
+    }
+
+    /// Get the arguments as a vec of JSON values.
+    pub fn into_vec(self) -> Vec<Value> {
+        self.values
+    }
+}
+
+impl From<ScriptArgs> for Vec<Value> {
+    fn from(args: ScriptArgs) -> Self {
+        args.into_vec()
+    }
+}
@@ -0,0 +1,147 @@
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use serde_json::Value;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::common::command::Command;
+use crate::error::WebDriverResult;
+use crate::session::handle::SessionHandle;
+use crate::upstream::Element;
+use crate::{By, ElementRefHelper, WebElement};
+
+/// The web element identifier for shadow roots, as defined by the W3C WebDriver spec.
+const SHADOW_ROOT_IDENTIFIER: &str = "shadow-6066-11e4-a52e-4f735466cecf";
+
+/// The ShadowRoot struct encapsulates the shadow root of an element.
+///
+/// Elements within the shadow DOM cannot be found by querying from the document,
+/// but they can be found by querying from the ShadowRoot.
+///
+/// NOTE: WebDriver does not support XPath selectors within a shadow root.
+///
+/// # Example:
+/// ```no_run
+/// # use thirtyfour::prelude::*;
+/// # use thirtyfour::support::block_on;
+/// #
+/// # fn main() -> WebDriverResult<()> {
+/// #     block_on(async {
+/// #         let caps = DesiredCapabilities::chrome();
+/// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+/// let elem = driver.find(By::Id("my-component")).await?;
+/// let shadow_root = elem.shadow_root().await?;
+/// let button = shadow_root.find(By::Css("button")).await?;
+/// button.click().await?;
+/// #         driver.quit().await?;
+/// #         Ok(())
+/// #     })
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct ShadowRoot {
+    shadow_id: String,
+    /// The underlying session handle.
+    pub handle: Arc<SessionHandle>,
+}
+
+impl fmt::Debug for ShadowRoot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ShadowRoot").field("shadow_id", &self.shadow_id).finish()
+    }
+}
+
+impl PartialEq for ShadowRoot {
+    fn eq(&self, other: &Self) -> bool {
+        self.shadow_id == other.shadow_id
+    }
+}
+
+impl Eq for ShadowRoot {}
+
+impl ShadowRoot {
+    /// Create a new ShadowRoot from the JSON value returned by the WebDriver.
+    pub(crate) fn from_json(value: Value, handle: Arc<SessionHandle>) -> WebDriverResult<Self> {
+        let shadow_ref: ElementRefHelper = serde_json::from_value(value)?;
+        Ok(Self {
+            shadow_id: shadow_ref.id().to_string(),
+            handle,
+        })
+    }
+
+    /// Get the internal shadow root id, as assigned by the WebDriver.
+    pub fn shadow_id(&self) -> &str {
+        &self.shadow_id
+    }
+
+    /// Convert the specified element reference returned by the WebDriver into a WebElement.
+    fn wrap_element_ref(&self, value: Value) -> WebDriverResult<WebElement> {
+        let element_ref: ElementRefHelper = serde_json::from_value(value)?;
+        let element = Element::from_element_id(self.handle.client.clone(), element_ref.into());
+        Ok(self.handle.wrap_element(element))
+    }
+
+    /// Search for an element within this shadow root.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// let elem = driver.find(By::Id("my-component")).await?;
+    /// let shadow_root = elem.shadow_root().await?;
+    /// let child_elem = shadow_root.find(By::Css("button")).await?;
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn find(&self, by: impl Into<By>) -> WebDriverResult<WebElement> {
+        let cmd = Command::FindElementFromShadowRoot(self.shadow_id.clone(), by.into());
+        let value = self.handle.cmd(cmd).await?;
+        self.wrap_element_ref(value)
+    }
+
+    /// Search for all elements within this shadow root that match the specified selector.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// let elem = driver.find(By::Id("my-component")).await?;
+    /// let shadow_root = elem.shadow_root().await?;
+    /// let child_elems = shadow_root.find_all(By::Css("button")).await?;
+    /// for child_elem in child_elems {
+    ///     assert_eq!(child_elem.tag_name().await?, "button");
+    /// }
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn find_all(&self, by: impl Into<By>) -> WebDriverResult<Vec<WebElement>> {
+        let cmd = Command::FindElementsFromShadowRoot(self.shadow_id.clone(), by.into());
+        let value = self.handle.cmd(cmd).await?;
+        let values: Vec<Value> = serde_json::from_value(value)?;
+        values.into_iter().map(|v| self.wrap_element_ref(v)).collect()
+    }
+}
+
+impl Serialize for ShadowRoot {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry(SHADOW_ROOT_IDENTIFIER, &self.shadow_id)?;
+        map.end()
+    }
+}
@@ -0,0 +1,128 @@
+use crate::error::WebDriverResult;
+use crate::session::handle::SessionHandle;
+use serde_json::Value;
+use std::sync::Arc;
+
+/// Handle for reading and writing Web Storage (`localStorage` or `sessionStorage`)
+/// for the origin of the current page.
+///
+/// This is typically created via `WebDriver::local_storage()` or
+/// `WebDriver::session_storage()`. All operations use JavaScript, so the current
+/// page must have an origin that supports Web Storage (e.g. not `about:blank`).
+///
+/// # Example:
+/// ```no_run
+/// # use thirtyfour::prelude::*;
+/// # use thirtyfour::support::block_on;
+/// #
+/// # fn main() -> WebDriverResult<()> {
+/// #     block_on(async {
+/// #         let caps = DesiredCapabilities::chrome();
+/// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+/// driver.goto("https://www.rust-lang.org/").await?;
+/// let storage = driver.local_storage();
+/// storage.set("token", "abc123").await?;
+/// assert_eq!(storage.get("token").await?, Some("abc123".to_string()));
+/// storage.remove("token").await?;
+/// #         driver.quit().await?;
+/// #         Ok(())
+/// #     })
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Storage {
+    handle: Arc<SessionHandle>,
+    /// The name of the storage object on `window`.
+    name: &'static str,
+}
+
+impl Storage {
+    /// Run the specified script, with the storage object as the first argument.
+    async fn run(&self, script: &str, mut args: Vec<Value>) -> WebDriverResult<Value> {
+        args.insert(0, Value::from(self.name));
+        let ret = self.handle.execute(script, args).await?;
+        Ok(ret.json().clone())
+    }
+
+    /// Get the value for the specified key, or `None` if the key does not exist.
+    pub async fn get(&self, key: impl AsRef<str>) -> WebDriverResult<Option<String>> {
+        let ret = self
+            .run("return window[arguments[0]].getItem(arguments[1]);", vec![key.as_ref().into()])
+            .await?;
+        Ok(serde_json::from_value(ret)?)
+    }
+
+    /// Set the value for the specified key.
+    pub async fn set(&self, key: impl AsRef<str>, value: impl AsRef<str>) -> WebDriverResult<()> {
+        self.run(
+            "window[arguments[0]].setItem(arguments[1], arguments[2]);",
+            vec![key.as_ref().into(), value.as_ref().into()],
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Remove the specified key. This does nothing if the key does not exist.
+    pub async fn remove(&self, key: impl AsRef<str>) -> WebDriverResult<()> {
+        self.run("window[arguments[0]].removeItem(arguments[1]);", vec![key.as_ref().into()])
+            .await?;
+        Ok(())
+    }
+
+    /// Remove all keys.
+    pub async fn clear(&self) -> WebDriverResult<()> {
+        self.run("window[arguments[0]].clear();", Vec::new()).await?;
+        Ok(())
+    }
+
+    /// Get all keys.
+    pub async fn keys(&self) -> WebDriverResult<Vec<String>> {
+        let ret = self
+            .run(
+                r#"
+                const storage = window[arguments[0]];
+                const keys = [];
+                for (let i = 0; i < storage.length; i++) {
+                    keys.push(storage.key(i));
+                }
+                return keys;
+                "#,
+                Vec::new(),
+            )
+            .await?;
+        Ok(serde_json::from_value(ret)?)
+    }
+
+    /// Get the number of keys.
+    pub async fn len(&self) -> WebDriverResult<usize> {
+        let ret = self.run("return window[arguments[0]].length;", Vec::new()).await?;
+        Ok(serde_json::from_value(ret)?)
+    }
+
+    /// Return true if there are no keys.
+    pub async fn is_empty(&self) -> WebDriverResult<bool> {
+        Ok(self.len().await? == 0)
+    }
+}
+
+impl SessionHandle {
+    /// Get a handle to the `localStorage` for the origin of the current page.
+    ///
+    /// See [`Storage`] for an example.
+    pub fn local_storage(self: &Arc<SessionHandle>) -> Storage {
+        Storage {
+            handle: self.clone(),
+            name: "localStorage",
+        }
+    }
+
+    /// Get a handle to the `sessionStorage` for the origin of the current page.
+    ///
+    /// See [`Storage`] for an example.
+    pub fn session_storage(self: &Arc<SessionHandle>) -> Storage {
+        Storage {
+            handle: self.clone(),
+            name: "sessionStorage",
+        }
+    }
+}
@@ -1,10 +1,10 @@
 use crate::error::WebDriverErrorDetails;
 use crate::session::handle::SessionHandle;
-use crate::WindowHandle;
 use crate::{
     error::{WebDriverError, WebDriverResult},
     Alert, WebElement,
 };
+use crate::{FrameSelector, WindowHandle, WindowType};
 use std::sync::Arc;
 
 /// Struct for switching between frames/windows/alerts.
@@ -159,7 +159,9 @@ impl SessionHandle {
     /// # }
     /// ```
     pub async fn active_element(self: &Arc<SessionHandle>) -> WebDriverResult<WebElement> {
-        let elem = self.client.active_element().await?;
+        let elem = self
+            .logged("active_element", "GET element/active", self.client.active_element())
+            .await?;
         Ok(self.wrap_element(elem))
     }
 
@@ -186,7 +188,7 @@ impl SessionHandle {
     /// # }
     /// ```
     pub async fn enter_default_frame(&self) -> WebDriverResult<()> {
-        self.client.enter_frame(None).await?;
+        self.logged("enter_frame", "POST frame", self.client.enter_frame(None)).await?;
         Ok(())
     }
 
@@ -212,7 +214,51 @@ impl SessionHandle {
     /// # }
     /// ```
     pub async fn enter_frame(&self, frame_number: u16) -> WebDriverResult<()> {
-        self.client.enter_frame(Some(frame_number)).await?;
+        self.logged("enter_frame", "POST frame", self.client.enter_frame(Some(frame_number)))
+            .await?;
+        Ok(())
+    }
+
+    /// Switch to the frame specified by the [`FrameSelector`].
+    ///
+    /// This issues the W3C `Switch To Frame` command. Use `FrameSelector::Default`
+    /// to switch back to the top-level browsing context.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// // Enter the first iframe.
+    /// driver.switch_to_frame(FrameSelector::Index(0)).await?;
+    /// driver.switch_to_frame(FrameSelector::Default).await?;
+    ///
+    /// // Enter an iframe via its element.
+    /// let elem_iframe = driver.find(By::Id("iframeid1")).await?;
+    /// driver.switch_to_frame(FrameSelector::Element(elem_iframe)).await?;
+    /// // We can now search for elements within the iframe.
+    /// let elem = driver.find(By::Id("button1")).await?;
+    /// elem.click().await?;
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn switch_to_frame(&self, frame: FrameSelector) -> WebDriverResult<()> {
+        match frame {
+            FrameSelector::Default => {
+                self.logged("enter_frame", "POST frame", self.client.enter_frame(None)).await?
+            }
+            FrameSelector::Index(index) => {
+                self.logged("enter_frame", "POST frame", self.client.enter_frame(Some(index)))
+                    .await?
+            }
+            FrameSelector::Element(element) => element.element.enter_frame().await?,
+        }
         Ok(())
     }
 
@@ -242,10 +288,70 @@ impl SessionHandle {
     /// # }
     /// ```
     pub async fn enter_parent_frame(&self) -> WebDriverResult<()> {
-        self.client.enter_parent_frame().await?;
+        self.logged("enter_parent_frame", "POST frame/parent", self.client.enter_parent_frame())
+            .await?;
         Ok(())
     }
 
+    /// Switch back to the top-level browsing context (the main document).
+    ///
+    /// This is an alias for [`SessionHandle::enter_default_frame()`], named to match
+    /// `switch_to_frame()`. It resets the context to the top-level document regardless of
+    /// how many iframes deep the current context is.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// // Enter the outer iframe, then the iframe nested inside it.
+    /// driver.switch_to_frame(FrameSelector::Index(0)).await?;
+    /// driver.switch_to_frame(FrameSelector::Index(0)).await?;
+    /// // Jump straight back to the main document.
+    /// driver.switch_to_default_content().await?;
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn switch_to_default_content(&self) -> WebDriverResult<()> {
+        self.enter_default_frame().await
+    }
+
+    /// Switch to the parent of the current frame.
+    ///
+    /// This is an alias for [`SessionHandle::enter_parent_frame()`], named to match
+    /// `switch_to_frame()`. It only moves up a single level, which is useful when working
+    /// with nested iframes.
+    /// If the current context is already the top-level browsing context, this does nothing.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// // Enter the outer iframe, then the iframe nested inside it.
+    /// driver.switch_to_frame(FrameSelector::Index(0)).await?;
+    /// driver.switch_to_frame(FrameSelector::Index(0)).await?;
+    /// // Now go back up to the outer iframe.
+    /// driver.switch_to_parent_frame().await?;
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn switch_to_parent_frame(&self) -> WebDriverResult<()> {
+        self.enter_parent_frame().await
+    }
+
     /// Switch to the specified window.
     ///
     /// # Example:
@@ -272,7 +378,8 @@ impl SessionHandle {
     /// # }
     /// ```
     pub async fn switch_to_window(&self, handle: WindowHandle) -> WebDriverResult<()> {
-        self.client.switch_to_window(handle).await?;
+        self.logged("switch_to_window", "POST window", self.client.switch_to_window(handle))
+            .await?;
         Ok(())
     }
 
@@ -348,7 +455,8 @@ impl SessionHandle {
     /// # }
     /// ```
     pub async fn new_window(&self) -> WebDriverResult<WindowHandle> {
-        let response = self.client.new_window(false).await?;
+        let response =
+            self.logged("new_window", "POST window/new", self.client.new_window(false)).await?;
         Ok(response.handle)
     }
 
@@ -371,7 +479,47 @@ impl SessionHandle {
     /// # }
     /// ```
     pub async fn new_tab(&self) -> WebDriverResult<WindowHandle> {
-        let response = self.client.new_window(true).await?;
+        let response =
+            self.logged("new_window", "POST window/new", self.client.new_window(true)).await?;
         Ok(response.handle)
     }
+
+    /// Open a new tab or window, as specified by the [`WindowType`].
+    ///
+    /// Returns the handle of the new tab/window, along with the type of window that was
+    /// actually created. The WebDriver server treats the type as a hint only, so the
+    /// returned type may differ from the one requested.
+    ///
+    /// Note that this does not switch to the new tab/window.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// // Open a new tab and switch to it.
+    /// let (handle, _window_type) = driver.new_window_with_type(WindowType::Tab).await?;
+    /// driver.switch_to_window(handle).await?;
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn new_window_with_type(
+        &self,
+        window_type: WindowType,
+    ) -> WebDriverResult<(WindowHandle, WindowType)> {
+        let response = self
+            .logged(
+                "new_window",
+                "POST window/new",
+                self.client.new_window(window_type == WindowType::Tab),
+            )
+            .await?;
+        Ok((response.handle, response.typ.into()))
+    }
 }
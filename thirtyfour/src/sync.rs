@@ -0,0 +1,309 @@
+//! Blocking wrappers around `WebDriver` and `WebElement`.
+//!
+//! These are useful for small scripts and tools that do not want to set up an async
+//! runtime. Each wrapper owns a handle to a dedicated tokio runtime, and each method
+//! blocks the current thread until the corresponding async method has completed.
+//!
+//! Only the most commonly used methods are wrapped. For everything else, use `block_on()`
+//! with the inner async type, which is available via `inner()`.
+//!
+//! NOTE: These wrappers cannot be used within an active tokio runtime.
+//!
+//! Unlike the async `WebDriver`, dropping the blocking `WebDriver` ends the session and
+//! blocks until the browser is closed, unless `persist()` was called. The session is only
+//! ended there if no `WebElement` from this session is still alive, so prefer calling
+//! `quit()` explicitly, which also reports any error.
+//!
+//! Requires the `sync` feature.
+//!
+//! # Example:
+//! ```no_run
+//! use thirtyfour::prelude::*;
+//! use thirtyfour::sync::WebDriver;
+//!
+//! fn main() -> WebDriverResult<()> {
+//!     let caps = DesiredCapabilities::chrome();
+//!     let driver = WebDriver::new("http://localhost:4444", caps)?;
+//!     driver.goto("https://www.rust-lang.org/")?;
+//!     let elem = driver.find(By::Css("h1"))?;
+//!     println!("{}", elem.text()?);
+//!
+//!     // Use the async API for anything that is not wrapped.
+//!     let handle = driver.block_on(driver.inner().window())?;
+//!     println!("{handle:?}");
+//!     driver.quit()?;
+//!     Ok(())
+//! }
+//! ```
+use crate::common::config::WebDriverConfig;
+use crate::error::WebDriverResult;
+use crate::session::scriptret::ScriptRet;
+use crate::{By, Capabilities};
+use futures::Future;
+use serde_json::Value;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+use url::Url;
+
+/// Blocking wrapper around [`crate::WebDriver`].
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug)]
+pub struct WebDriver {
+    // NOTE: The driver must be dropped before the runtime it uses.
+    inner: crate::WebDriver,
+    rt: Arc<Runtime>,
+    /// Whether the session was ended by `quit()` or should be kept open, so that it is not
+    /// ended on drop.
+    keep_session: AtomicBool,
+}
+
+impl WebDriver {
+    /// Create a new WebDriver session, using a new tokio runtime.
+    ///
+    /// See [`crate::WebDriver::new()`] for more details.
+    pub fn new<C>(server_url: &str, capabilities: C) -> WebDriverResult<Self>
+    where
+        C: Into<Capabilities>,
+    {
+        Self::new_with_config(server_url, capabilities, WebDriverConfig::default())
+    }
+
+    /// Create a new WebDriver session with the specified configuration, using a new
+    /// tokio runtime.
+    ///
+    /// See [`crate::WebDriver::new_with_config()`] for more details.
+    pub fn new_with_config<C>(
+        server_url: &str,
+        capabilities: C,
+        config: WebDriverConfig,
+    ) -> WebDriverResult<Self>
+    where
+        C: Into<Capabilities>,
+    {
+        let rt = Arc::new(Runtime::new()?);
+        let inner =
+            rt.block_on(crate::WebDriver::new_with_config(server_url, capabilities, config))?;
+        Ok(Self {
+            inner,
+            rt,
+            keep_session: AtomicBool::new(false),
+        })
+    }
+
+    /// Get the inner async `WebDriver`.
+    pub fn inner(&self) -> &crate::WebDriver {
+        &self.inner
+    }
+
+    /// Run the specified future on the runtime for this session and block the current
+    /// thread waiting for the result.
+    ///
+    /// Use this to call async methods that are not wrapped.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.rt.block_on(future)
+    }
+
+    fn wrap(&self, element: crate::WebElement) -> WebElement {
+        WebElement {
+            inner: element,
+            rt: self.rt.clone(),
+        }
+    }
+
+    /// Navigate to the specified URL.
+    pub fn goto(&self, url: impl AsRef<str>) -> WebDriverResult<()> {
+        self.block_on(self.inner.goto(url))
+    }
+
+    /// Get the current URL.
+    pub fn current_url(&self) -> WebDriverResult<Url> {
+        self.block_on(self.inner.current_url())
+    }
+
+    /// Get the page source as a String.
+    pub fn source(&self) -> WebDriverResult<String> {
+        self.block_on(self.inner.source())
+    }
+
+    /// Get the page title.
+    pub fn title(&self) -> WebDriverResult<String> {
+        self.block_on(self.inner.title())
+    }
+
+    /// Go back. This is equivalent to clicking the browser's back button.
+    pub fn back(&self) -> WebDriverResult<()> {
+        self.block_on(self.inner.back())
+    }
+
+    /// Go forward. This is equivalent to clicking the browser's forward button.
+    pub fn forward(&self) -> WebDriverResult<()> {
+        self.block_on(self.inner.forward())
+    }
+
+    /// Refresh the current page.
+    pub fn refresh(&self) -> WebDriverResult<()> {
+        self.block_on(self.inner.refresh())
+    }
+
+    /// Search for an element on the current page using the specified selector.
+    pub fn find(&self, by: impl Into<By>) -> WebDriverResult<WebElement> {
+        let element = self.block_on(self.inner.find(by))?;
+        Ok(self.wrap(element))
+    }
+
+    /// Search for all elements on the current page that match the specified selector.
+    pub fn find_all(&self, by: impl Into<By>) -> WebDriverResult<Vec<WebElement>> {
+        let elements = self.block_on(self.inner.find_all(by))?;
+        Ok(elements.into_iter().map(|x| self.wrap(x)).collect())
+    }
+
+    /// Execute the specified Javascript synchronously and return the result.
+    ///
+    /// See [`crate::session::handle::SessionHandle::execute()`] for more details.
+    pub fn execute(&self, script: &str, args: Vec<Value>) -> WebDriverResult<ScriptRet> {
+        self.block_on(self.inner.execute(script, args))
+    }
+
+    /// Take a screenshot of the current window and return it as PNG bytes.
+    pub fn screenshot_as_png(&self) -> WebDriverResult<Vec<u8>> {
+        self.block_on(self.inner.screenshot_as_png())
+    }
+
+    /// Keep the browser session running after this `WebDriver` has been dropped.
+    ///
+    /// See [`crate::WebDriver::persist()`] for more details.
+    pub fn persist(&self) -> WebDriverResult<()> {
+        self.block_on(self.inner.persist())?;
+        self.keep_session.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// End the webdriver session and close the browser.
+    pub fn quit(self) -> WebDriverResult<()> {
+        self.keep_session.store(true, Ordering::SeqCst);
+        self.rt.block_on(self.inner.clone().quit())
+    }
+}
+
+impl Drop for WebDriver {
+    fn drop(&mut self) {
+        // If an element still refers to the session, it may still be in use.
+        if self.keep_session.load(Ordering::SeqCst) || Arc::strong_count(&self.inner.handle) > 1 {
+            return;
+        }
+        if let Err(e) = self.rt.block_on(self.inner.clone().quit()) {
+            log::warn!("failed to end the WebDriver session on drop: {e}");
+        }
+    }
+}
+
+/// Blocking wrapper around [`crate::WebElement`].
+///
+/// Elements are returned by the `find()` and `find_all()` methods of the sync
+/// [`WebDriver`] and `WebElement`.
+#[derive(Debug, Clone)]
+pub struct WebElement {
+    inner: crate::WebElement,
+    rt: Arc<Runtime>,
+}
+
+impl WebElement {
+    /// Get the inner async `WebElement`.
+    pub fn inner(&self) -> &crate::WebElement {
+        &self.inner
+    }
+
+    /// Run the specified future on the runtime for this session and block the current
+    /// thread waiting for the result.
+    ///
+    /// Use this to call async methods that are not wrapped.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.rt.block_on(future)
+    }
+
+    fn wrap(&self, element: crate::WebElement) -> WebElement {
+        WebElement {
+            inner: element,
+            rt: self.rt.clone(),
+        }
+    }
+
+    /// Get the tag name for this element.
+    pub fn tag_name(&self) -> WebDriverResult<String> {
+        self.block_on(self.inner.tag_name())
+    }
+
+    /// Get the text contents for this element.
+    pub fn text(&self) -> WebDriverResult<String> {
+        self.block_on(self.inner.text())
+    }
+
+    /// Convenience method for getting the (optional) value property of this element.
+    pub fn value(&self) -> WebDriverResult<Option<String>> {
+        self.block_on(self.inner.value())
+    }
+
+    /// Click the element.
+    pub fn click(&self) -> WebDriverResult<()> {
+        self.block_on(self.inner.click())
+    }
+
+    /// Clear this element.
+    pub fn clear(&self) -> WebDriverResult<()> {
+        self.block_on(self.inner.clear())
+    }
+
+    /// Send the specified input.
+    pub fn send_keys(&self, keys: impl AsRef<str>) -> WebDriverResult<()> {
+        self.block_on(self.inner.send_keys(keys))
+    }
+
+    /// Get the specified property.
+    pub fn prop(&self, name: &str) -> WebDriverResult<Option<String>> {
+        self.block_on(self.inner.prop(name))
+    }
+
+    /// Get the specified attribute.
+    pub fn attr(&self, name: &str) -> WebDriverResult<Option<String>> {
+        self.block_on(self.inner.attr(name))
+    }
+
+    /// Get the specified CSS property.
+    pub fn css_value(&self, name: &str) -> WebDriverResult<String> {
+        self.block_on(self.inner.css_value(name))
+    }
+
+    /// Return true if the element is currently selected, otherwise false.
+    pub fn is_selected(&self) -> WebDriverResult<bool> {
+        self.block_on(self.inner.is_selected())
+    }
+
+    /// Return true if the element is currently displayed, otherwise false.
+    pub fn is_displayed(&self) -> WebDriverResult<bool> {
+        self.block_on(self.inner.is_displayed())
+    }
+
+    /// Return true if the element is currently enabled, otherwise false.
+    pub fn is_enabled(&self) -> WebDriverResult<bool> {
+        self.block_on(self.inner.is_enabled())
+    }
+
+    /// Search for a child element of this element using the specified selector.
+    pub fn find(&self, by: impl Into<By>) -> WebDriverResult<WebElement> {
+        let element = self.block_on(self.inner.find(by))?;
+        Ok(self.wrap(element))
+    }
+
+    /// Search for all child elements of this element that match the specified selector.
+    pub fn find_all(&self, by: impl Into<By>) -> WebDriverResult<Vec<WebElement>> {
+        let elements = self.block_on(self.inner.find_all(by))?;
+        Ok(elements.into_iter().map(|x| self.wrap(x)).collect())
+    }
+
+    /// Take a screenshot of this element and return it as PNG bytes.
+    pub fn screenshot_as_png(&self) -> WebDriverResult<Vec<u8>> {
+        self.block_on(self.inner.screenshot_as_png())
+    }
+}
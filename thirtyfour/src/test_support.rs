@@ -0,0 +1,279 @@
+//! Support for testing code that uses `thirtyfour`, without a browser or WebDriver server.
+//!
+//! [`MockConnection`] is an in-memory WebDriver server that returns canned responses
+//! and records every request it receives. This makes it possible to unit test
+//! page objects and other code built on `WebDriver` deterministically.
+//!
+//! Requires the `test-support` feature.
+//!
+//! # Example:
+//! ```
+//! # use thirtyfour::prelude::*;
+//! # use thirtyfour::support::block_on;
+//! use serde_json::json;
+//! use thirtyfour::hyper::Method;
+//! use thirtyfour::test_support::MockConnection;
+//!
+//! # fn main() -> WebDriverResult<()> {
+//! #     block_on(async {
+//! let mock = MockConnection::new();
+//! mock.push_response(Method::GET, "title", json!("Mock Title"));
+//!
+//! let driver = mock.connect(DesiredCapabilities::chrome()).await?;
+//! assert_eq!(driver.title().await?, "Mock Title");
+//!
+//! // Navigating first gets the current url, to resolve relative urls.
+//! mock.push_response(Method::GET, "url", json!("about:blank"));
+//! driver.goto("https://www.rust-lang.org/").await?;
+//! driver.quit().await?;
+//!
+//! let requests = mock.requests();
+//! let request = requests.iter().find(|r| r.method == Method::POST && r.command == "url");
+//! assert_eq!(request.unwrap().body, json!({ "url": "https://www.rust-lang.org/" }));
+//! #         Ok(())
+//! #     })
+//! # }
+//! ```
+use crate::common::config::WebDriverConfig;
+use crate::error::WebDriverResult;
+use crate::{Capabilities, WebDriver};
+use futures::future::{ready, Ready};
+use hyper::client::connect::{Connected, Connection};
+use hyper::server::conn::Http;
+use hyper::service::{service_fn, Service};
+use hyper::{Body, Method, Request, Response, StatusCode, Uri};
+use parking_lot::Mutex;
+use serde_json::{json, Value};
+use std::collections::{HashMap, VecDeque};
+use std::convert::Infallible;
+use std::io;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tokio::io::{AsyncRead, AsyncWrite, DuplexStream, ReadBuf};
+
+/// The session id used by the mock server.
+pub const MOCK_SESSION_ID: &str = "mock-session";
+
+/// The url used to connect to the mock server. Nothing is actually sent over the network.
+const MOCK_SERVER_URL: &str = "http://mock.localhost";
+
+/// A request received by the mock server.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockRequest {
+    /// The HTTP method.
+    pub method: Method,
+    /// The command, which is the request path relative to the session.
+    ///
+    /// For example `title` or `element/{element_id}/click`. Creating the session uses
+    /// the command `session`, and ending it uses an empty command.
+    pub command: String,
+    /// The JSON request body, or `null` if there was none.
+    pub body: Value,
+}
+
+/// A canned response from the mock server.
+#[derive(Debug, Clone)]
+enum MockResponse {
+    Value(Value),
+    Error {
+        error: String,
+        message: String,
+    },
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    responses: Mutex<HashMap<(Method, String), VecDeque<MockResponse>>>,
+    requests: Mutex<Vec<MockRequest>>,
+}
+
+/// An in-memory WebDriver server with canned responses.
+///
+/// Responses are queued per command (see [`MockRequest::command`]) and each one is used
+/// once, in the order they were added. Commands without a queued response succeed with
+/// a `null` value, and creating the session succeeds with the session id
+/// [`MOCK_SESSION_ID`].
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug, Clone, Default)]
+pub struct MockConnection {
+    state: Arc<MockState>,
+}
+
+impl MockConnection {
+    /// Create a new mock server with no queued responses.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue a successful response with the specified value for the specified command.
+    pub fn push_response(&self, method: Method, command: impl Into<String>, value: Value) {
+        self.push(method, command.into(), MockResponse::Value(value));
+    }
+
+    /// Queue an error response for the specified command.
+    ///
+    /// The `error` must be a W3C WebDriver error code, such as `no such element`.
+    pub fn push_error(
+        &self,
+        method: Method,
+        command: impl Into<String>,
+        error: impl Into<String>,
+        message: impl Into<String>,
+    ) {
+        let response = MockResponse::Error {
+            error: error.into(),
+            message: message.into(),
+        };
+        self.push(method, command.into(), response);
+    }
+
+    fn push(&self, method: Method, command: String, response: MockResponse) {
+        self.state.responses.lock().entry((method, command)).or_default().push_back(response);
+    }
+
+    /// Get all requests received so far, in order.
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.state.requests.lock().clone()
+    }
+
+    /// Get a connector that connects to this mock server.
+    ///
+    /// This can be used with [`WebDriver::new_with_connector`] to supply a custom
+    /// `WebDriverConfig`. Any server url can be used.
+    pub fn connector(&self) -> MockConnector {
+        MockConnector {
+            state: self.state.clone(),
+        }
+    }
+
+    /// Create a new `WebDriver` session on this mock server.
+    pub async fn connect<C>(&self, capabilities: C) -> WebDriverResult<WebDriver>
+    where
+        C: Into<Capabilities>,
+    {
+        WebDriver::new_with_connector(
+            MOCK_SERVER_URL,
+            capabilities,
+            WebDriverConfig::default(),
+            self.connector(),
+        )
+        .await
+    }
+}
+
+/// Handle a single request to the mock server.
+async fn handle_request(
+    req: Request<Body>,
+    state: Arc<MockState>,
+) -> Result<Response<Body>, Infallible> {
+    let method = req.method().clone();
+    let path = req.uri().path().trim_matches('/').to_string();
+    let command = match path.strip_prefix(&format!("session/{MOCK_SESSION_ID}")) {
+        Some(rest) => rest.trim_start_matches('/').to_string(),
+        None => path,
+    };
+    let body = hyper::body::to_bytes(req.into_body()).await.unwrap_or_default();
+    let body = serde_json::from_slice(&body).unwrap_or(Value::Null);
+    state.requests.lock().push(MockRequest {
+        method: method.clone(),
+        command: command.clone(),
+        body,
+    });
+
+    let response = state
+        .responses
+        .lock()
+        .get_mut(&(method.clone(), command.clone()))
+        .and_then(|x| x.pop_front());
+    let (status, value) = match response {
+        Some(MockResponse::Value(value)) => (StatusCode::OK, value),
+        Some(MockResponse::Error {
+            error,
+            message,
+        }) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            json!({ "error": error, "message": message, "stacktrace": "" }),
+        ),
+        None if method == Method::POST && command == "session" => {
+            (StatusCode::OK, json!({ "sessionId": MOCK_SESSION_ID, "capabilities": {} }))
+        }
+        None => (StatusCode::OK, Value::Null),
+    };
+
+    let response = Response::builder()
+        .status(status)
+        .header(hyper::header::CONTENT_TYPE, "application/json; charset=utf-8")
+        .body(Body::from(json!({ "value": value }).to_string()))
+        .expect("valid response");
+    Ok(response)
+}
+
+/// A connector for a [`MockConnection`].
+///
+/// Each connection is served in memory by the mock server.
+#[derive(Debug, Clone)]
+pub struct MockConnector {
+    state: Arc<MockState>,
+}
+
+impl Service<Uri> for MockConnector {
+    type Response = MockStream;
+    type Error = Infallible;
+    type Future = Ready<Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, _uri: Uri) -> Self::Future {
+        let (client, server) = tokio::io::duplex(64 * 1024);
+        let state = self.state.clone();
+        let service = service_fn(move |req| handle_request(req, state.clone()));
+        tokio::spawn(async move {
+            if let Err(e) = Http::new().http1_only(true).serve_connection(server, service).await {
+                log::error!("mock connection failed: {e}");
+            }
+        });
+        ready(Ok(MockStream(client)))
+    }
+}
+
+/// An in-memory connection to a [`MockConnection`].
+#[derive(Debug)]
+pub struct MockStream(DuplexStream);
+
+impl Connection for MockStream {
+    fn connected(&self) -> Connected {
+        Connected::new()
+    }
+}
+
+impl AsyncRead for MockStream {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.0).poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for MockStream {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.0).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.0).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.0).poll_shutdown(cx)
+    }
+}
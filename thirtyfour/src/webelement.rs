@@ -1,4 +1,6 @@
 use crate::upstream::Element;
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
 use serde::ser::{Serialize, Serializer};
 use serde_json::Value;
 use std::fmt;
@@ -7,11 +9,13 @@ use std::sync::Arc;
 use tokio::fs::File;
 use tokio::io::AsyncWriteExt;
 
-use crate::error::WebDriverError;
+use crate::common::command::Command;
+use crate::error::{WebDriverError, WebDriverErrorDetails};
 use crate::js::SIMULATE_DRAG_AND_DROP;
 use crate::session::handle::SessionHandle;
 use crate::upstream::ElementRef;
-use crate::{common::types::ElementRect, error::WebDriverResult, By, ElementRefHelper};
+use crate::ScrollAlignment;
+use crate::{common::types::ElementRect, error::WebDriverResult, By, ElementRefHelper, ShadowRoot};
 
 /// The WebElement struct encapsulates a single element on a page.
 ///
@@ -54,6 +58,13 @@ use crate::{common::types::ElementRect, error::WebDriverResult, By, ElementRefHe
 /// Elements can be clicked using the `click()` method, and you can send
 /// input to an element using the `send_keys()` method.
 ///
+/// ## Serialization
+///
+/// A `WebElement` serializes to its W3C element reference, e.g.
+/// `{"element-6066-11e4-a52e-4f735466cecf": "<id>"}`. This can be passed directly as a
+/// script argument, logged, or stored and later turned back into a `WebElement` for the
+/// same session using [`WebElement::from_json`].
+///
 #[derive(Clone)]
 pub struct WebElement {
     pub(crate) element: Element,
@@ -67,9 +78,11 @@ impl fmt::Debug for WebElement {
     }
 }
 
+/// Two elements are equal if they refer to the same DOM node, which is the case when they
+/// have the same element id within the same session.
 impl PartialEq for WebElement {
     fn eq(&self, other: &Self) -> bool {
-        self.element_id() == other.element_id()
+        self.handle.session_id == other.handle.session_id && self.element_id() == other.element_id()
     }
 }
 
@@ -120,6 +133,10 @@ impl WebElement {
 
     /// Get the internal element id for this element.
     ///
+    /// The element id is assigned by the WebDriver and is stable for the lifetime of the
+    /// session, so it can be used to check whether two elements refer to the same DOM node.
+    /// Use `element_id().as_ref()` to get the id as a string slice.
+    ///
     /// NOTE: If you want the `id` property of an element,
     ///       use [`WebElement::id`] instead.
     pub fn element_id(&self) -> ElementRef {
@@ -145,7 +162,10 @@ impl WebElement {
     /// # }
     /// ```
     pub async fn rect(&self) -> WebDriverResult<ElementRect> {
-        let (x, y, w, h) = self.element.rectangle().await?;
+        let (x, y, w, h) = self
+            .handle
+            .logged("rect", "GET element/{element_id}/rect", self.element.rectangle())
+            .await?;
         Ok(ElementRect {
             x,
             y,
@@ -159,6 +179,55 @@ impl WebElement {
         self.rect().await
     }
 
+    /// Get the computed ARIA role for this WebElement.
+    ///
+    /// The role is computed by the browser from the accessibility tree, so it may differ
+    /// from the value of the `role` attribute.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// let elem = driver.find(By::Id("button1")).await?;
+    /// assert_eq!(elem.computed_role().await?, "button");
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn computed_role(&self) -> WebDriverResult<String> {
+        let v = self.handle.cmd(Command::GetComputedRole(self.element_id())).await?;
+        Ok(serde_json::from_value(v)?)
+    }
+
+    /// Get the computed accessible label (accessible name) for this WebElement.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// let elem = driver.find(By::Id("button1")).await?;
+    /// let label = elem.computed_label().await?;
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn computed_label(&self) -> WebDriverResult<String> {
+        let v = self.handle.cmd(Command::GetComputedLabel(self.element_id())).await?;
+        Ok(serde_json::from_value(v)?)
+    }
+
     /// Get the tag name for this WebElement.
     ///
     /// # Example:
@@ -178,7 +247,10 @@ impl WebElement {
     /// # }
     /// ```
     pub async fn tag_name(&self) -> WebDriverResult<String> {
-        Ok(self.element.tag_name().await?)
+        Ok(self
+            .handle
+            .logged("tag_name", "GET element/{element_id}/name", self.element.tag_name())
+            .await?)
     }
 
     /// Get the class name for this WebElement.
@@ -245,7 +317,7 @@ impl WebElement {
     /// # }
     /// ```
     pub async fn text(&self) -> WebDriverResult<String> {
-        Ok(self.element.text().await?)
+        Ok(self.handle.logged("text", "GET element/{element_id}/text", self.element.text()).await?)
     }
 
     /// Convenience method for getting the (optional) value property of this element.
@@ -272,7 +344,9 @@ impl WebElement {
     /// # }
     /// ```
     pub async fn click(&self) -> WebDriverResult<()> {
-        self.element.click().await?;
+        self.handle
+            .logged("click", "POST element/{element_id}/click", self.element.click())
+            .await?;
         Ok(())
     }
 
@@ -295,11 +369,72 @@ impl WebElement {
     /// # }
     /// ```
     pub async fn clear(&self) -> WebDriverResult<()> {
-        Ok(self.element.clear().await?)
+        Ok(self
+            .handle
+            .logged("clear", "POST element/{element_id}/clear", self.element.clear())
+            .await?)
+    }
+
+    /// Submit the form containing this WebElement, or this WebElement if it is a form.
+    ///
+    /// The W3C WebDriver spec has no command for submitting forms, so this uses JavaScript
+    /// to call `form.requestSubmit()`. This validates the form and fires the `submit` event,
+    /// just like clicking a submit button. On older browsers without `requestSubmit()`,
+    /// `form.submit()` is used instead, which skips both validation and the `submit` event.
+    ///
+    /// Returns `WebDriverError::InvalidArgument` if the element is not inside a form.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// let elem = driver.find(By::Name("input1")).await?;
+    /// elem.send_keys("thirtyfour").await?;
+    /// elem.submit().await?;
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn submit(&self) -> WebDriverResult<()> {
+        let ret = self
+            .handle
+            .execute(
+                r#"
+                const elem = arguments[0];
+                const form = elem.tagName === "FORM" ? elem : (elem.form || elem.closest("form"));
+                if (!form) {
+                    return false;
+                }
+                if (typeof form.requestSubmit === "function") {
+                    form.requestSubmit();
+                } else {
+                    form.submit();
+                }
+                return true;
+                "#,
+                vec![self.to_json()?],
+            )
+            .await?;
+        if !ret.convert::<bool>()? {
+            return Err(WebDriverError::InvalidArgument(WebDriverErrorDetails::new(
+                "submit requires an element inside a <form>",
+            )));
+        }
+        Ok(())
     }
 
     /// Get the specified property.
     ///
+    /// Properties reflect the live state of the DOM element. For example, the `value`
+    /// property of an input is its current contents, whereas the `value` attribute
+    /// (see `attr()`) is the initial value from the HTML.
+    ///
     /// # Example:
     /// ```no_run
     /// # use thirtyfour::prelude::*;
@@ -321,7 +456,47 @@ impl WebElement {
     /// # }
     /// ```
     pub async fn prop(&self, name: &str) -> WebDriverResult<Option<String>> {
-        Ok(self.element.prop(name).await?)
+        Ok(self
+            .handle
+            .logged("prop", "GET element/{element_id}/property/{name}", self.element.prop(name))
+            .await?)
+    }
+
+    /// Get the specified property as JSON.
+    ///
+    /// Unlike `prop()`, this supports properties of any type, such as numbers
+    /// (e.g. `scrollTop`) or arrays and objects.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// let elem = driver.find(By::Id("my-list")).await?;
+    /// let count = elem.prop_json("childElementCount").await?;
+    /// assert_eq!(count, Some(serde_json::json!(3)));
+    ///
+    /// // If a property is not found, None is returned.
+    /// assert_eq!(elem.prop_json("invalid-property").await?, None);
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn prop_json(&self, name: &str) -> WebDriverResult<Option<Value>> {
+        let v = self
+            .handle
+            .client
+            .issue_cmd(Command::GetElementProperty(self.element_id(), name.to_string()))
+            .await?;
+        Ok(match v {
+            Value::Null => None,
+            v => Some(v),
+        })
     }
 
     /// Get the specified property.
@@ -332,6 +507,10 @@ impl WebElement {
 
     /// Get the specified attribute.
     ///
+    /// Attributes are the values set in the HTML (or via `setAttribute()`), and do not
+    /// change as the user interacts with the element. Use `prop()` for the current state,
+    /// such as the contents of an input or whether a checkbox is checked.
+    ///
     /// # Example:
     /// ```no_run
     /// # use thirtyfour::prelude::*;
@@ -353,7 +532,10 @@ impl WebElement {
     /// # }
     /// ```
     pub async fn attr(&self, name: &str) -> WebDriverResult<Option<String>> {
-        Ok(self.element.attr(name).await?)
+        Ok(self
+            .handle
+            .logged("attr", "GET element/{element_id}/attribute/{name}", self.element.attr(name))
+            .await?)
     }
 
     /// Get the specified attribute.
@@ -385,7 +567,14 @@ impl WebElement {
     /// # }
     /// ```
     pub async fn css_value(&self, name: &str) -> WebDriverResult<String> {
-        Ok(self.element.css_value(name).await?)
+        Ok(self
+            .handle
+            .logged(
+                "css_value",
+                "GET element/{element_id}/css/{name}",
+                self.element.css_value(name),
+            )
+            .await?)
     }
 
     /// Get the specified CSS property.
@@ -395,8 +584,32 @@ impl WebElement {
     }
 
     /// Return true if the WebElement is currently selected, otherwise false.
+    ///
+    /// This applies to checkboxes, radio buttons and `<option>` elements.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// let elem = driver.find(By::Css("input[type='checkbox']")).await?;
+    /// assert!(!elem.is_selected().await?);
+    /// elem.click().await?;
+    /// assert!(elem.is_selected().await?);
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
     pub async fn is_selected(&self) -> WebDriverResult<bool> {
-        Ok(self.element.is_selected().await?)
+        Ok(self
+            .handle
+            .logged("is_selected", "GET element/{element_id}/selected", self.element.is_selected())
+            .await?)
     }
 
     /// Return true if the WebElement is currently displayed, otherwise false.
@@ -418,7 +631,14 @@ impl WebElement {
     /// # }
     /// ```
     pub async fn is_displayed(&self) -> WebDriverResult<bool> {
-        Ok(self.element.is_displayed().await?)
+        Ok(self
+            .handle
+            .logged(
+                "is_displayed",
+                "GET element/{element_id}/displayed",
+                self.element.is_displayed(),
+            )
+            .await?)
     }
 
     /// Return true if the WebElement is currently enabled, otherwise false.
@@ -440,7 +660,10 @@ impl WebElement {
     /// # }
     /// ```
     pub async fn is_enabled(&self) -> WebDriverResult<bool> {
-        Ok(self.element.is_enabled().await?)
+        Ok(self
+            .handle
+            .logged("is_enabled", "GET element/{element_id}/enabled", self.element.is_enabled())
+            .await?)
     }
 
     /// Return true if the WebElement is currently clickable (visible and enabled),
@@ -504,6 +727,34 @@ impl WebElement {
         Ok(present)
     }
 
+    /// Return true if the WebElement is stale, i.e. it has been removed from the DOM
+    /// (or the page has changed) since it was found.
+    ///
+    /// This is the opposite of [`WebElement::is_present`], and is useful for checking
+    /// that an element has gone away after an action. Errors other than a stale element
+    /// reference are still returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// let elem = driver.find(By::Id("dialog")).await?;
+    /// driver.find(By::Id("close-dialog")).await?.click().await?;
+    /// assert!(elem.is_stale().await?);
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn is_stale(&self) -> WebDriverResult<bool> {
+        Ok(!self.is_present().await?)
+    }
+
     /// Search for a child element of this WebElement using the specified selector.
     ///
     /// **NOTE**: For more powerful element queries including polling and filters, see the
@@ -528,8 +779,11 @@ impl WebElement {
     /// # }
     /// ```
     pub async fn find(&self, by: impl Into<By>) -> WebDriverResult<WebElement> {
-        let by = by.into();
-        let elem = self.element.find(by.locator()).await?;
+        let by = by.into().for_element();
+        let elem = self
+            .handle
+            .logged("find", "POST element/{element_id}/element", self.element.find(by.locator()))
+            .await?;
         Ok(self.handle.wrap_element(elem))
     }
 
@@ -539,6 +793,38 @@ impl WebElement {
         self.find(by).await
     }
 
+    /// Search for a child element of this WebElement using the specified selector,
+    /// returning `None` if no element matches.
+    ///
+    /// Unlike [`WebElement::find`], this does not return an error when the element is
+    /// not found. Any other error is still returned.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// let elem = driver.find(By::Id("my-element-id")).await?;
+    /// if let Some(child_elem) = elem.try_find(By::Tag("button")).await? {
+    ///     child_elem.click().await?;
+    /// }
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn try_find(&self, by: impl Into<By>) -> WebDriverResult<Option<WebElement>> {
+        match self.find(by).await {
+            Ok(elem) => Ok(Some(elem)),
+            Err(WebDriverError::NoSuchElement(_)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
     /// Search for all child elements of this WebElement that match the specified selector.
     ///
     /// **NOTE**: For more powerful element queries including polling and filters, see the
@@ -566,8 +852,15 @@ impl WebElement {
     /// # }
     /// ```
     pub async fn find_all(&self, by: impl Into<By>) -> WebDriverResult<Vec<WebElement>> {
-        let by = by.into();
-        let elems = self.element.find_all(by.locator()).await?;
+        let by = by.into().for_element();
+        let elems = self
+            .handle
+            .logged(
+                "find_all",
+                "POST element/{element_id}/elements",
+                self.element.find_all(by.locator()),
+            )
+            .await?;
         Ok(elems.into_iter().map(|x| self.handle.wrap_element(x)).collect())
     }
 
@@ -596,7 +889,9 @@ impl WebElement {
     /// # }
     /// ```
     ///
-    /// You can also send special key combinations like this:
+    /// You can also send special keys and key combinations like this. Modifier keys such as
+    /// `Key::Control` stay pressed until `Key::Null` is sent or the end of the input, so they do
+    /// not affect the next call. Use `Key::chord()` to release them within a single call.
     /// ```no_run
     /// # use thirtyfour::prelude::*;
     /// # use thirtyfour::support::block_on;
@@ -609,18 +904,85 @@ impl WebElement {
     /// elem.send_keys("selenium").await?;
     /// elem.send_keys(Key::Control + "a").await?;
     /// elem.send_keys("thirtyfour" + &Key::Enter).await?;
+    /// elem.send_keys(&*Key::Tab).await?;
     /// #         driver.quit().await?;
     /// #         Ok(())
     /// #     })
     /// # }
     /// ```
     pub async fn send_keys(&self, keys: impl AsRef<str>) -> WebDriverResult<()> {
-        Ok(self.element.send_keys(keys.as_ref()).await?)
+        Ok(self
+            .handle
+            .logged(
+                "send_keys",
+                "POST element/{element_id}/value",
+                self.element.send_keys(keys.as_ref()),
+            )
+            .await?)
+    }
+
+    /// Select the specified local file in this `<input type="file">` element.
+    ///
+    /// The path is converted to an absolute path before being sent to the WebDriver,
+    /// which must therefore be running on the same machine. For a remote WebDriver,
+    /// use `WebDriver::upload_file()` first and send the returned path with `send_keys()`.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// use std::path::Path;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// let elem = driver.find(By::Css("input[type='file']")).await?;
+    /// elem.send_file(Path::new("report.pdf")).await?;
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn send_file(&self, path: &Path) -> WebDriverResult<()> {
+        let tag_name = self.tag_name().await?;
+        let input_type = self.attr("type").await?.unwrap_or_default();
+        if !tag_name.eq_ignore_ascii_case("input") || !input_type.eq_ignore_ascii_case("file") {
+            return Err(WebDriverError::InvalidArgument(WebDriverErrorDetails::new(
+                "send_file requires an <input type=\"file\"> element",
+            )));
+        }
+
+        let path = tokio::fs::canonicalize(path).await?;
+        let path = path.to_str().ok_or_else(|| {
+            WebDriverError::InvalidArgument(WebDriverErrorDetails::new(format!(
+                "invalid file path: {}",
+                path.display()
+            )))
+        })?;
+        self.send_keys(path).await
     }
 
     /// Take a screenshot of this WebElement and return it as PNG bytes.
     pub async fn screenshot_as_png(&self) -> WebDriverResult<Vec<u8>> {
-        Ok(self.element.screenshot().await?)
+        Ok(self
+            .handle
+            .logged("screenshot", "GET element/{element_id}/screenshot", self.element.screenshot())
+            .await?)
+    }
+
+    /// Take a screenshot of this WebElement and return it as an image.
+    ///
+    /// See `WebDriver::screenshot_as_image()` for details. Requires the `image` feature.
+    #[cfg(feature = "image")]
+    pub async fn screenshot_as_image(&self) -> WebDriverResult<image::DynamicImage> {
+        crate::session::screenshot::decode_png(&self.screenshot_as_png().await?)
+    }
+
+    /// Take a screenshot of this WebElement and return it as a base64-encoded PNG string.
+    pub async fn screenshot_as_base64(&self) -> WebDriverResult<String> {
+        let png = self.screenshot_as_png().await?;
+        Ok(BASE64_STANDARD.encode(png))
     }
 
     /// Take a screenshot of this WebElement and write it to the specified filename.
@@ -673,10 +1035,33 @@ impl WebElement {
     /// # }
     /// ```
     pub async fn scroll_into_view(&self) -> WebDriverResult<()> {
+        self.scroll_into_view_with(ScrollAlignment::Center).await
+    }
+
+    /// Scroll this element into view using JavaScript, with the specified alignment.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// use thirtyfour::ScrollAlignment;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// let elem = driver.find(By::Id("button1")).await?;
+    /// elem.scroll_into_view_with(ScrollAlignment::Start).await?;
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn scroll_into_view_with(&self, alignment: ScrollAlignment) -> WebDriverResult<()> {
         self.handle
             .execute(
-                r#"arguments[0].scrollIntoView({block: "center", inline: "center"});"#,
-                vec![self.to_json()?],
+                r#"arguments[0].scrollIntoView({block: arguments[1], inline: arguments[1]});"#,
+                vec![self.to_json()?, serde_json::to_value(alignment)?],
             )
             .await?;
         Ok(())
@@ -684,6 +1069,9 @@ impl WebElement {
 
     /// Get the innerHtml property of this element.
     ///
+    /// This is the markup of its children, serialized by the browser. It is
+    /// returned as-is, so whitespace is not trimmed.
+    ///
     /// # Example:
     /// ```no_run
     /// # use thirtyfour::prelude::*;
@@ -706,6 +1094,9 @@ impl WebElement {
 
     /// Get the outerHtml property of this element.
     ///
+    /// This is the markup of the element itself and its children, serialized by the browser. It is
+    /// returned as-is, so whitespace is not trimmed.
+    ///
     /// # Example:
     /// ```no_run
     /// # use thirtyfour::prelude::*;
@@ -736,6 +1127,36 @@ impl WebElement {
         ret.element()
     }
 
+    /// Get the shadow root of this element, using the W3C `Get Element Shadow Root` command.
+    ///
+    /// Call this method on the element containing the `#shadowRoot` node.
+    /// You can then use the returned `ShadowRoot` to query elements within the shadow DOM.
+    ///
+    /// Returns `WebDriverError::NoSuchShadowRoot` if this element has no shadow root.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # use thirtyfour::prelude::*;
+    /// # use thirtyfour::support::block_on;
+    /// #
+    /// # fn main() -> WebDriverResult<()> {
+    /// #     block_on(async {
+    /// #         let caps = DesiredCapabilities::chrome();
+    /// #         let driver = WebDriver::new("http://localhost:4444", caps).await?;
+    /// let elem = driver.find(By::Id("my-component")).await?;
+    /// let shadow_root = elem.shadow_root().await?;
+    /// let child_elem = shadow_root.find(By::Css("button")).await?;
+    /// #         driver.quit().await?;
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn shadow_root(&self) -> WebDriverResult<ShadowRoot> {
+        let cmd = Command::GetElementShadowRoot(self.element_id());
+        let value = self.handle.cmd(cmd).await?;
+        ShadowRoot::from_json(value, self.handle.clone())
+    }
+
     /// Switch to the specified iframe element.
     ///
     /// # Example:
@@ -758,7 +1179,7 @@ impl WebElement {
     /// # }
     /// ```
     pub async fn enter_frame(self) -> WebDriverResult<()> {
-        self.element.enter_frame().await?;
+        self.handle.logged("enter_frame", "POST frame", self.element.enter_frame()).await?;
         Ok(())
     }
 
@@ -783,7 +1204,11 @@ impl WebElement {
     /// ```
     pub async fn js_drag_to(&self, target: &Self) -> WebDriverResult<()> {
         self.handle
-            .execute(SIMULATE_DRAG_AND_DROP, vec![self.to_json()?, target.to_json()?])
+            .execute_atom(
+                "simulateDragDrop",
+                SIMULATE_DRAG_AND_DROP,
+                vec![self.to_json()?, target.to_json()?],
+            )
             .await?;
         Ok(())
     }
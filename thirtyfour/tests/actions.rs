@@ -1,6 +1,7 @@
 //! Actions tests
-use crate::common::{drag_to_url, sample_page_url};
+use crate::common::{clicks_url, drag_to_url, sample_page_url};
 use serial_test::serial;
+use std::time::{Duration, Instant};
 use thirtyfour::prelude::*;
 
 mod common;
@@ -118,6 +119,112 @@ async fn actions_drag_and_drop(c: WebDriver, port: u16) -> Result<(), WebDriverE
     Ok(())
 }
 
+async fn actions_scroll(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
+    let sample_url = sample_page_url(port);
+    c.goto(&sample_url).await?;
+
+    // Make sure the page is tall enough to scroll.
+    c.execute(
+        r#"
+        const target = document.createElement("div");
+        target.id = "scroll-target";
+        target.innerHTML = "Scroll Target";
+        target.style.margin = "5000px 0";
+        document.body.appendChild(target);
+        "#,
+        Vec::new(),
+    )
+    .await?;
+
+    c.action_chain().scroll_by(0, 500).perform().await?;
+    let scroll_y: f64 = c.execute("return window.scrollY;", Vec::new()).await?.convert()?;
+    assert_eq!(scroll_y, 500.0);
+
+    let elem = c.find(By::Id("scroll-target")).await?;
+    c.action_chain().scroll_to(&elem).perform().await?;
+    let top: f64 = c
+        .execute("return arguments[0].getBoundingClientRect().top;", vec![elem.to_json()?])
+        .await?
+        .convert()?;
+    assert!(top.abs() < 1.0, "element should be scrolled to the top of the viewport");
+    Ok(())
+}
+
+async fn actions_key_modifier(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
+    let sample_url = sample_page_url(port);
+    c.goto(&sample_url).await?;
+
+    let elem = c.find(By::Id("text-input")).await?;
+    elem.send_keys("selenium").await?;
+    assert_eq!(elem.prop("value").await?.unwrap(), "selenium");
+
+    // Select all text while holding Control, then delete it, all in a single perform().
+    // Releasing the Shift key, which was never pressed, should be a no-op.
+    c.action_chain()
+        .click_element(&elem)
+        .key_down(Key::Control)
+        .key_down('a')
+        .key_up('a')
+        .key_up(Key::Control)
+        .key_up(Key::Shift)
+        .key_down(Key::Backspace)
+        .key_up(Key::Backspace)
+        .perform()
+        .await?;
+    assert_eq!(elem.prop("value").await?.unwrap(), "");
+    Ok(())
+}
+
+async fn actions_pause(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
+    let sample_url = sample_page_url(port);
+    c.goto(&sample_url).await?;
+
+    let elem = c.find(By::Id("text-input")).await?;
+    let start = Instant::now();
+    c.action_chain()
+        .click_element(&elem)
+        .pause(Duration::from_millis(300))
+        .key_down('a')
+        .key_up('a')
+        .perform()
+        .await?;
+    assert!(start.elapsed() >= Duration::from_millis(300));
+    assert_eq!(elem.prop("value").await?.unwrap(), "a");
+    Ok(())
+}
+
+async fn actions_drag_by_offset(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
+    // Set window size to avoid moving the cursor out-of-bounds during actions.
+    c.set_window_rect(0, 0, 800, 800).await?;
+
+    let sample_url = sample_page_url(port);
+    c.goto(&sample_url).await?;
+
+    let source = c.find(By::Id("pointer-drag-source")).await?;
+    source.scroll_into_view().await?;
+    c.action_chain().drag_and_drop_element_by_offset(&source, 40, 20).perform().await?;
+
+    // The output is only written once the mouse button has been released.
+    let output = c.find(By::Id("pointer-drag-output")).await?;
+    assert_eq!(output.text().await?, "40,20");
+    Ok(())
+}
+
+async fn actions_context_and_double_click(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
+    let clicks_url = clicks_url(port);
+    c.goto(&clicks_url).await?;
+
+    let elem = c.find(By::Id("button-clicks")).await?;
+    let output = c.find(By::Id("clicks-output")).await?;
+
+    c.action_chain().context_click_element(&elem).perform().await?;
+    assert_eq!(output.text().await?, "context click");
+
+    c.action_chain().double_click_element(&elem).perform().await?;
+    assert_eq!(output.text().await?, "double click");
+    Ok(())
+}
+
 mod firefox {
     use super::*;
 
@@ -150,6 +257,36 @@ mod firefox {
     fn actions_drag_and_drop_test() {
         local_tester!(actions_drag_and_drop, "firefox");
     }
+
+    #[test]
+    #[serial]
+    fn actions_scroll_test() {
+        local_tester!(actions_scroll, "firefox");
+    }
+
+    #[test]
+    #[serial]
+    fn actions_key_modifier_test() {
+        local_tester!(actions_key_modifier, "firefox");
+    }
+
+    #[test]
+    #[serial]
+    fn actions_pause_test() {
+        local_tester!(actions_pause, "firefox");
+    }
+
+    #[test]
+    #[serial]
+    fn actions_drag_by_offset_test() {
+        local_tester!(actions_drag_by_offset, "firefox");
+    }
+
+    #[test]
+    #[serial]
+    fn actions_context_and_double_click_test() {
+        local_tester!(actions_context_and_double_click, "firefox");
+    }
 }
 
 mod chrome {
@@ -179,4 +316,29 @@ mod chrome {
     fn actions_drag_and_drop_test() {
         local_tester!(actions_drag_and_drop, "chrome");
     }
+
+    #[test]
+    fn actions_scroll_test() {
+        local_tester!(actions_scroll, "chrome");
+    }
+
+    #[test]
+    fn actions_key_modifier_test() {
+        local_tester!(actions_key_modifier, "chrome");
+    }
+
+    #[test]
+    fn actions_pause_test() {
+        local_tester!(actions_pause, "chrome");
+    }
+
+    #[test]
+    fn actions_drag_by_offset_test() {
+        local_tester!(actions_drag_by_offset, "chrome");
+    }
+
+    #[test]
+    fn actions_context_and_double_click_test() {
+        local_tester!(actions_context_and_double_click, "chrome");
+    }
 }
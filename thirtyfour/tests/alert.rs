@@ -53,6 +53,17 @@ async fn alert_text(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
     Ok(())
 }
 
+async fn alert_missing(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
+    let sample_url = sample_page_url(port);
+    c.goto(&sample_url).await?;
+    assert!(matches!(c.get_alert_text().await, Err(WebDriverError::NoSuchAlert(..))));
+    assert!(matches!(c.accept_alert().await, Err(WebDriverError::NoSuchAlert(..))));
+    assert!(matches!(c.dismiss_alert().await, Err(WebDriverError::NoSuchAlert(..))));
+    assert!(matches!(c.send_alert_text("text").await, Err(WebDriverError::NoSuchAlert(..))));
+
+    Ok(())
+}
+
 mod firefox {
     use super::*;
 
@@ -73,6 +84,12 @@ mod firefox {
     fn alert_text_test() {
         local_tester!(alert_text, "firefox");
     }
+
+    #[test]
+    #[serial]
+    fn alert_missing_test() {
+        local_tester!(alert_missing, "firefox");
+    }
 }
 
 mod chrome {
@@ -92,4 +109,9 @@ mod chrome {
     fn alert_text_test() {
         local_tester!(alert_text, "chrome");
     }
+
+    #[test]
+    fn alert_missing_test() {
+        local_tester!(alert_missing, "chrome");
+    }
 }
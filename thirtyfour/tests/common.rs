@@ -187,3 +187,7 @@ pub fn other_page_url(port: u16) -> String {
 pub fn drag_to_url(port: u16) -> String {
     format!("http://localhost:{}/drag_to.html", port)
 }
+
+pub fn clicks_url(port: u16) -> String {
+    format!("http://localhost:{}/clicks.html", port)
+}
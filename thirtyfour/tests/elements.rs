@@ -1,7 +1,9 @@
 //! Element tests
 use crate::common::sample_page_url;
 use serial_test::serial;
+use std::path::Path;
 use thirtyfour::prelude::*;
+use thirtyfour::ScrollAlignment;
 
 mod common;
 
@@ -40,6 +42,10 @@ async fn element_prop(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
     assert_eq!(elem.prop("id").await?.unwrap(), "checkbox-option-1");
     assert_eq!(elem.prop("checked").await?.unwrap(), "false");
     assert!(elem.attr("invalid-property").await?.is_none());
+
+    assert_eq!(elem.prop_json("checked").await?, Some(serde_json::json!(false)));
+    assert_eq!(elem.prop_json("tabIndex").await?, Some(serde_json::json!(0)));
+    assert_eq!(elem.prop_json("invalid-property").await?, None);
     Ok(())
 }
 
@@ -143,6 +149,17 @@ async fn serialize_element(c: WebDriver, port: u16) -> Result<(), WebDriverError
     // This does the same thing.
     elem.scroll_into_view().await?;
 
+    // The element serializes to its element reference, which can be turned back into an element.
+    let json = serde_json::to_value(&elem)?;
+    assert_eq!(
+        json,
+        serde_json::json!({ "element-6066-11e4-a52e-4f735466cecf": elem.element_id().to_string() })
+    );
+    assert_eq!(json, elem.to_json()?);
+    let restored = WebElement::from_json(json, c.handle.clone())?;
+    assert_eq!(restored, elem);
+    assert_eq!(restored.id().await?.as_deref(), Some("other_page_id"));
+
     // Check that it fails with an invalid serialization (from a previous run of the test)
     let json = r#"{"element-6066-11e4-a52e-4f735466cecf":"fbe5004d-ec8b-4c7b-ad08-642c55d84505"}"#;
 
@@ -158,6 +175,40 @@ async fn serialize_element(c: WebDriver, port: u16) -> Result<(), WebDriverError
     c.close_window().await
 }
 
+async fn element_computed_role_and_label(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
+    let url = sample_page_url(port);
+    c.goto(&url).await?;
+
+    let elem = c.find(By::Id("button-copy")).await?;
+    assert_eq!(elem.computed_role().await?, "button");
+    assert_eq!(elem.computed_label().await?, "Copy");
+
+    Ok(())
+}
+
+async fn element_shadow_root(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
+    let url = sample_page_url(port);
+    c.goto(&url).await?;
+
+    // Elements in the shadow DOM cannot be found from the document.
+    c.find(By::Id("shadow-button"))
+        .await
+        .expect_err("should not find the button in the shadow DOM");
+
+    let host = c.find(By::Id("shadow-host")).await?;
+    let shadow_root = host.shadow_root().await?;
+    let button = shadow_root.find(By::Id("shadow-button")).await?;
+    assert_eq!(button.text().await?, "Shadow Button");
+    let buttons = shadow_root.find_all(By::Tag("button")).await?;
+    assert_eq!(buttons.len(), 1);
+    assert_eq!(buttons[0], button);
+
+    let elem = c.find(By::Id("text-input")).await?;
+    assert!(matches!(elem.shadow_root().await, Err(WebDriverError::NoSuchShadowRoot(..))));
+
+    Ok(())
+}
+
 async fn element_screenshot(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
     let url = sample_page_url(port);
     c.goto(&url).await?;
@@ -167,6 +218,9 @@ async fn element_screenshot(c: WebDriver, port: u16) -> Result<(), WebDriverErro
     let screenshot_data = elem.screenshot_as_png().await?;
     assert!(!screenshot_data.is_empty(), "screenshot data is empty");
 
+    let screenshot_base64 = elem.screenshot_as_base64().await?;
+    assert!(!screenshot_base64.is_empty(), "screenshot base64 data is empty");
+
     Ok(())
 }
 
@@ -200,6 +254,130 @@ async fn element_get_parent(c: WebDriver, port: u16) -> Result<(), WebDriverErro
     Ok(())
 }
 
+async fn element_send_file(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
+    let sample_url = sample_page_url(port);
+    c.goto(&sample_url).await?;
+
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/test_html/other_page.html");
+    let elem = c.find(By::Id("file-input")).await?;
+    elem.send_file(&path).await?;
+    let value = elem.prop("value").await?.unwrap();
+    assert!(value.ends_with("other_page.html"), "unexpected value: {value}");
+
+    // Only file inputs are supported.
+    let elem = c.find(By::Id("text-input")).await?;
+    assert!(matches!(elem.send_file(&path).await, Err(WebDriverError::InvalidArgument(_))));
+    Ok(())
+}
+
+async fn element_submit(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
+    let sample_url = sample_page_url(port);
+    c.goto(&sample_url).await?;
+
+    let elem = c.find(By::Id("submit-input")).await?;
+    elem.send_keys("thirtyfour").await?;
+    elem.submit().await?;
+    let output = c.find(By::Id("submit-output")).await?;
+    assert_eq!(output.text().await?, "submitted thirtyfour");
+
+    // Elements outside a form cannot be submitted.
+    let elem = c.find(By::Id("text-input")).await?;
+    assert!(matches!(elem.submit().await, Err(WebDriverError::InvalidArgument(_))));
+    Ok(())
+}
+
+async fn element_scroll_into_view(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
+    let sample_url = sample_page_url(port);
+    c.goto(&sample_url).await?;
+
+    let elem = c.find(By::Id("footer")).await?;
+    for alignment in [
+        ScrollAlignment::Start,
+        ScrollAlignment::Center,
+        ScrollAlignment::End,
+        ScrollAlignment::Nearest,
+    ] {
+        c.execute("window.scrollTo(0, 0);", Vec::new()).await?;
+        elem.scroll_into_view_with(alignment).await?;
+        let ret = c
+            .execute(
+                r#"
+                const rect = arguments[0].getBoundingClientRect();
+                return rect.top >= 0 && rect.bottom <= window.innerHeight;
+                "#,
+                vec![elem.to_json()?],
+            )
+            .await?;
+        assert!(ret.convert::<bool>()?, "element not in view for {alignment:?}");
+    }
+    Ok(())
+}
+
+async fn element_send_keys_chord(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
+    let sample_url = sample_page_url(port);
+    c.goto(&sample_url).await?;
+    let elem = c.find(By::Id("text-input")).await?;
+    elem.send_keys("thirtyfour").await?;
+    let modifier = if cfg!(target_os = "macos") {
+        Key::Command
+    } else {
+        Key::Control
+    };
+
+    // The modifier is released after the chord, so the next key replaces the selection.
+    elem.send_keys(Key::chord(&[modifier], 'a') + "selenium").await?;
+    assert_eq!(elem.value().await?.unwrap(), "selenium");
+    Ok(())
+}
+
+async fn element_attr_vs_prop(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
+    let sample_url = sample_page_url(port);
+    c.goto(&sample_url).await?;
+    let elem = c.find(By::Id("value-input")).await?;
+    elem.send_keys(" and typed").await?;
+
+    // The attribute keeps the initial value from the HTML, while the property is live.
+    assert_eq!(elem.attr("value").await?.unwrap(), "initial");
+    assert_eq!(elem.prop("value").await?.unwrap(), "initial and typed");
+    assert_eq!(elem.prop_json("value").await?, Some(serde_json::json!("initial and typed")));
+    Ok(())
+}
+
+async fn element_equality(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
+    let url = sample_page_url(port);
+    c.goto(&url).await?;
+
+    // Finding the same node twice gives equal elements.
+    let select = c.find(By::Id("select1")).await?;
+    assert_eq!(select, c.find(By::Css("#select1")).await?);
+    assert_eq!(select.element_id(), c.find(By::Id("select1")).await?.element_id());
+    assert_ne!(select, c.find(By::Id("select2")).await?);
+
+    // Overlapping results can be deduplicated.
+    let mut options = c.find_all(By::Css("#select1 option")).await?;
+    options.extend(c.find_all(By::Css("option")).await?);
+    let mut unique: Vec<WebElement> = Vec::new();
+    for option in options {
+        if !unique.contains(&option) {
+            unique.push(option);
+        }
+    }
+    assert_eq!(unique.len(), c.find_all(By::Css("option")).await?.len());
+    Ok(())
+}
+
+async fn element_is_stale(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
+    let url = sample_page_url(port);
+    c.goto(&url).await?;
+    let elem = c.find(By::Id("other_page_id")).await?;
+    assert!(!elem.is_stale().await?);
+
+    c.execute("arguments[0].remove();", vec![elem.to_json()?]).await?;
+    assert!(elem.is_stale().await?);
+    assert!(!elem.is_present().await?);
+    Ok(())
+}
+
 mod firefox {
     use super::*;
 
@@ -275,6 +453,18 @@ mod firefox {
         local_tester!(element_screenshot, "firefox");
     }
 
+    #[test]
+    #[serial]
+    fn element_shadow_root_test() {
+        local_tester!(element_shadow_root, "firefox");
+    }
+
+    #[test]
+    #[serial]
+    fn element_computed_role_and_label_test() {
+        local_tester!(element_computed_role_and_label, "firefox");
+    }
+
     #[test]
     #[serial]
     fn element_focus_test() {
@@ -292,6 +482,48 @@ mod firefox {
     fn element_get_parent_test() {
         local_tester!(element_get_parent, "firefox");
     }
+
+    #[test]
+    #[serial]
+    fn element_send_file_test() {
+        local_tester!(element_send_file, "firefox");
+    }
+
+    #[test]
+    #[serial]
+    fn element_submit_test() {
+        local_tester!(element_submit, "firefox");
+    }
+
+    #[test]
+    #[serial]
+    fn element_scroll_into_view_test() {
+        local_tester!(element_scroll_into_view, "firefox");
+    }
+
+    #[test]
+    #[serial]
+    fn element_send_keys_chord_test() {
+        local_tester!(element_send_keys_chord, "firefox");
+    }
+
+    #[test]
+    #[serial]
+    fn element_attr_vs_prop_test() {
+        local_tester!(element_attr_vs_prop, "firefox");
+    }
+
+    #[test]
+    #[serial]
+    fn element_equality_test() {
+        local_tester!(element_equality, "firefox");
+    }
+
+    #[test]
+    #[serial]
+    fn element_is_stale_test() {
+        local_tester!(element_is_stale, "firefox");
+    }
 }
 
 mod chrome {
@@ -357,6 +589,16 @@ mod chrome {
         local_tester!(element_screenshot, "chrome");
     }
 
+    #[test]
+    fn element_shadow_root_test() {
+        local_tester!(element_shadow_root, "chrome");
+    }
+
+    #[test]
+    fn element_computed_role_and_label_test() {
+        local_tester!(element_computed_role_and_label, "chrome");
+    }
+
     #[test]
     fn element_focus_test() {
         local_tester!(element_focus, "chrome");
@@ -371,4 +613,39 @@ mod chrome {
     fn element_get_parent_test() {
         local_tester!(element_get_parent, "chrome");
     }
+
+    #[test]
+    fn element_send_file_test() {
+        local_tester!(element_send_file, "chrome");
+    }
+
+    #[test]
+    fn element_submit_test() {
+        local_tester!(element_submit, "chrome");
+    }
+
+    #[test]
+    fn element_scroll_into_view_test() {
+        local_tester!(element_scroll_into_view, "chrome");
+    }
+
+    #[test]
+    fn element_send_keys_chord_test() {
+        local_tester!(element_send_keys_chord, "chrome");
+    }
+
+    #[test]
+    fn element_attr_vs_prop_test() {
+        local_tester!(element_attr_vs_prop, "chrome");
+    }
+
+    #[test]
+    fn element_equality_test() {
+        local_tester!(element_equality, "chrome");
+    }
+
+    #[test]
+    fn element_is_stale_test() {
+        local_tester!(element_is_stale, "chrome");
+    }
 }
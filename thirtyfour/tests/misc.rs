@@ -1,7 +1,8 @@
 //! Tests that don't make use of external websites.
-use std::time::Duration;
+use std::time::{Duration, UNIX_EPOCH};
 
 use cookie::SameSite;
+use serde::Deserialize;
 use serial_test::serial;
 use thirtyfour::prelude::*;
 
@@ -138,6 +139,127 @@ async fn handle_cookies_test(c: WebDriver) -> Result<(), WebDriverError> {
     Ok(())
 }
 
+// Verifies that the SameSite attribute survives a round-trip via the WebDriver.
+async fn cookie_same_site(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
+    let url = sample_page_url(port);
+    c.goto(&url).await?;
+
+    let mut cookie = Cookie::new("samesitetest", "thirtyfour");
+    cookie.set_path("/");
+    cookie.set_same_site(Some(SameSite::Strict));
+    c.add_cookie(cookie.clone()).await?;
+
+    let cookie = c.get_named_cookie("samesitetest").await?;
+    assert_eq!(cookie.value(), "thirtyfour");
+    assert_eq!(cookie.same_site(), Some(SameSite::Strict));
+
+    c.delete_all_cookies().await?;
+    Ok(())
+}
+
+async fn cookie_expiry(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
+    let url = sample_page_url(port);
+    c.goto(&url).await?;
+
+    // The WebDriver protocol uses whole seconds, so use an expiry without sub-second precision.
+    let expiry = UNIX_EPOCH + Duration::from_secs(4_000_000_000);
+    let cookie = Cookie::new("expirytest", "thirtyfour").with_expiry(expiry);
+    c.add_cookie(cookie).await?;
+
+    let cookie = c.get_named_cookie("expirytest").await?;
+    assert_eq!(cookie.expiry(), Some(expiry));
+
+    c.delete_all_cookies().await?;
+    Ok(())
+}
+
+async fn session_id(c: WebDriver, _: u16) -> Result<(), WebDriverError> {
+    let id = c.client.session_id().await?.expect("session id should be set");
+    assert!(!id.is_empty());
+    assert_eq!(c.session_id().as_str(), id);
+    assert_eq!(c.session_id().to_string(), id);
+    Ok(())
+}
+
+async fn execute_return_values(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Info {
+        name: String,
+        count: u32,
+    }
+
+    let url = sample_page_url(port);
+    c.goto(&url).await?;
+
+    // Deserialize into a custom type.
+    let info: Info =
+        c.execute(r#"return { name: "thirtyfour", count: 30 };"#, vec![]).await?.convert()?;
+    assert_eq!(
+        info,
+        Info {
+            name: "thirtyfour".to_string(),
+            count: 30
+        }
+    );
+
+    // Single element.
+    let elem =
+        c.execute(r#"return document.getElementById("text-input");"#, vec![]).await?.element()?;
+    assert_eq!(elem, c.find(By::Id("text-input")).await?);
+
+    // List of elements.
+    let elems =
+        c.execute(r#"return document.querySelectorAll("select");"#, vec![]).await?.elements()?;
+    assert_eq!(elems.len(), c.find_all(By::Tag("select")).await?.len());
+    Ok(())
+}
+
+async fn execute_script_args(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
+    let url = sample_page_url(port);
+    c.goto(&url).await?;
+
+    let elem = c.find(By::Id("text-input")).await?;
+    let mut args = ScriptArgs::new();
+    args.push_element(&elem)?.push_value("thirtyfour")?;
+    let ret =
+        c.execute("arguments[0].value = arguments[1]; return arguments[0];", args.into()).await?;
+    assert_eq!(ret.element()?, elem);
+    assert_eq!(elem.prop("value").await?.unwrap(), "thirtyfour");
+    Ok(())
+}
+
+async fn web_storage(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
+    let sample_url = sample_page_url(port);
+    c.goto(&sample_url).await?;
+
+    for storage in [c.local_storage(), c.session_storage()] {
+        storage.clear().await?;
+        assert!(storage.is_empty().await?);
+        assert_eq!(storage.get("key1").await?, None);
+
+        storage.set("key1", "value1").await?;
+        storage.set("key2", "value2").await?;
+        assert_eq!(storage.get("key1").await?, Some("value1".to_string()));
+        assert_eq!(storage.len().await?, 2);
+        let mut keys = storage.keys().await?;
+        keys.sort();
+        assert_eq!(keys, vec!["key1", "key2"]);
+
+        storage.remove("key1").await?;
+        assert_eq!(storage.get("key1").await?, None);
+        assert_eq!(storage.len().await?, 1);
+
+        storage.clear().await?;
+        assert!(storage.is_empty().await?);
+    }
+
+    // The two storage areas are separate.
+    c.local_storage().set("key", "local").await?;
+    assert_eq!(c.session_storage().get("key").await?, None);
+    c.local_storage().clear().await?;
+    Ok(())
+}
+
 mod firefox {
     use super::*;
 
@@ -164,6 +286,42 @@ mod firefox {
     fn cookies_test() {
         tester!(handle_cookies_test, "firefox");
     }
+
+    #[test]
+    #[serial]
+    fn cookie_same_site_test() {
+        local_tester!(cookie_same_site, "firefox");
+    }
+
+    #[test]
+    #[serial]
+    fn cookie_expiry_test() {
+        local_tester!(cookie_expiry, "firefox");
+    }
+
+    #[test]
+    #[serial]
+    fn session_id_test() {
+        local_tester!(session_id, "firefox");
+    }
+
+    #[test]
+    #[serial]
+    fn execute_return_values_test() {
+        local_tester!(execute_return_values, "firefox");
+    }
+
+    #[test]
+    #[serial]
+    fn execute_script_args_test() {
+        local_tester!(execute_script_args, "firefox");
+    }
+
+    #[test]
+    #[serial]
+    fn web_storage_test() {
+        local_tester!(web_storage, "firefox");
+    }
 }
 
 mod chrome {
@@ -188,4 +346,34 @@ mod chrome {
     fn cookies_test() {
         tester!(handle_cookies_test, "chrome");
     }
+
+    #[test]
+    fn cookie_same_site_test() {
+        local_tester!(cookie_same_site, "chrome");
+    }
+
+    #[test]
+    fn cookie_expiry_test() {
+        local_tester!(cookie_expiry, "chrome");
+    }
+
+    #[test]
+    fn session_id_test() {
+        local_tester!(session_id, "chrome");
+    }
+
+    #[test]
+    fn execute_return_values_test() {
+        local_tester!(execute_return_values, "chrome");
+    }
+
+    #[test]
+    fn execute_script_args_test() {
+        local_tester!(execute_script_args, "chrome");
+    }
+
+    #[test]
+    fn web_storage_test() {
+        local_tester!(web_storage, "chrome");
+    }
 }
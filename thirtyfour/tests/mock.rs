@@ -0,0 +1,642 @@
+//! Tests for the mock WebDriver server in `thirtyfour::test_support`.
+//!
+//! These tests do not need a browser.
+#![cfg(feature = "test-support")]
+use assert_matches::assert_matches;
+use serde_json::json;
+use std::time::Duration;
+use thirtyfour::extensions::cdp::{NetworkConditions, PermissionState};
+use thirtyfour::hyper::Method;
+use thirtyfour::prelude::*;
+use thirtyfour::test_support::{MockConnection, MockRequest};
+use thirtyfour::{LogEntry, LogType, OptionRect, Rect, RelativeBy};
+
+const ELEMENT_KEY: &str = "element-6066-11e4-a52e-4f735466cecf";
+
+#[tokio::test]
+async fn find_and_click() -> Result<(), WebDriverError> {
+    let mock = MockConnection::new();
+    mock.push_response(Method::POST, "element", json!({ ELEMENT_KEY: "button-1" }));
+    let driver = mock.connect(DesiredCapabilities::chrome()).await?;
+    driver.find(By::Id("submit")).await?.click().await?;
+    driver.quit().await?;
+
+    let requests = mock.requests();
+    assert_eq!(requests[0].method, Method::POST);
+    assert_eq!(requests[0].command, "session");
+    assert!(requests.contains(&MockRequest {
+        method: Method::POST,
+        command: "element".to_string(),
+        body: json!({ "using": "xpath", "value": "//*[@id=\"submit\"]" }),
+    }));
+    assert!(requests
+        .iter()
+        .any(|r| r.method == Method::POST && r.command == "element/button-1/click"));
+    assert_eq!(requests.last().unwrap().method, Method::DELETE);
+    Ok(())
+}
+
+#[tokio::test]
+async fn find_id_from_element() -> Result<(), WebDriverError> {
+    let mock = MockConnection::new();
+    mock.push_response(Method::POST, "element", json!({ ELEMENT_KEY: "form-1" }));
+    mock.push_response(Method::POST, "element/form-1/element", json!({ ELEMENT_KEY: "input-1" }));
+    let driver = mock.connect(DesiredCapabilities::chrome()).await?;
+    let form = driver.find(By::Id("form")).await?;
+    form.find(By::Id("input")).await?;
+    driver.quit().await?;
+
+    // The search must stay within the element, so the id is not sent as an absolute XPath.
+    assert!(mock.requests().contains(&MockRequest {
+        method: Method::POST,
+        command: "element/form-1/element".to_string(),
+        body: json!({ "using": "css selector", "value": "[id=\"input\"]" }),
+    }));
+    Ok(())
+}
+
+#[tokio::test]
+async fn responses_in_order() -> Result<(), WebDriverError> {
+    let mock = MockConnection::new();
+    mock.push_response(Method::GET, "title", json!("First"));
+    mock.push_response(Method::GET, "title", json!("Second"));
+    let driver = mock.connect(DesiredCapabilities::chrome()).await?;
+    assert_eq!(driver.title().await?, "First");
+    assert_eq!(driver.title().await?, "Second");
+    driver.quit().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn error_response() -> Result<(), WebDriverError> {
+    let mock = MockConnection::new();
+    mock.push_error(Method::POST, "element", "no such element", "not found");
+    let driver = mock.connect(DesiredCapabilities::chrome()).await?;
+    assert_matches!(
+        driver.find(By::Id("missing")).await,
+        Err(WebDriverError::NoSuchElement(details)) if details.message == "not found"
+    );
+
+    // Errors other than a missing element are not swallowed by try_find().
+    mock.push_error(Method::POST, "element", "no such element", "not found");
+    mock.push_error(Method::POST, "element", "invalid selector", "bad selector");
+    assert!(driver.try_find(By::Id("missing")).await?.is_none());
+    assert_matches!(
+        driver.try_find(By::Id("missing")).await,
+        Err(WebDriverError::InvalidSelector(_))
+    );
+    driver.quit().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn cdp_command() -> Result<(), WebDriverError> {
+    let mock = MockConnection::new();
+    mock.push_response(Method::POST, "goog/cdp/execute", json!({ "product": "Chrome/1.0" }));
+    mock.push_error(Method::POST, "goog/cdp/execute", "unknown command", "");
+    let driver = mock.connect(DesiredCapabilities::chrome()).await?;
+
+    let params = json!({ "cacheDisabled": true });
+    let version = driver.execute_cdp_command("Network.setCacheDisabled", params.clone()).await?;
+    assert_eq!(version["product"], "Chrome/1.0");
+    assert!(mock.requests().contains(&MockRequest {
+        method: Method::POST,
+        command: "goog/cdp/execute".to_string(),
+        body: json!({ "cmd": "Network.setCacheDisabled", "params": params }),
+    }));
+
+    // Drivers that do not support CDP give a clear error.
+    assert_matches!(
+        driver.execute_cdp_command("Browser.getVersion", json!({})).await,
+        Err(WebDriverError::CustomError(message)) if message.contains("Chromium")
+    );
+    driver.quit().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn geolocation() -> Result<(), WebDriverError> {
+    let mock = MockConnection::new();
+    let driver = mock.connect(DesiredCapabilities::chrome()).await?;
+    driver.set_geolocation(-33.8688, 151.2093, 100.0).await?;
+    driver.clear_geolocation().await?;
+    driver.quit().await?;
+
+    let bodies: Vec<_> = mock
+        .requests()
+        .into_iter()
+        .filter(|r| r.command == "goog/cdp/execute")
+        .map(|r| r.body)
+        .collect();
+    assert_eq!(
+        bodies,
+        vec![
+            json!({
+                "cmd": "Emulation.setGeolocationOverride",
+                "params": { "latitude": -33.8688, "longitude": 151.2093, "accuracy": 100.0 }
+            }),
+            json!({ "cmd": "Emulation.clearGeolocationOverride", "params": {} }),
+        ]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn network_conditions() -> Result<(), WebDriverError> {
+    let mock = MockConnection::new();
+    let driver = mock.connect(DesiredCapabilities::chrome()).await?;
+    driver.apply_network_conditions(&NetworkConditions::slow_3g()).await?;
+    driver.apply_network_conditions(&NetworkConditions::offline()).await?;
+    driver.set_network_conditions(false, 500, i64::MAX, -1000).await?;
+    driver.quit().await?;
+
+    let bodies: Vec<_> = mock
+        .requests()
+        .into_iter()
+        .filter(|r| r.method == Method::POST && r.command == "chromium/network_conditions")
+        .map(|r| r.body)
+        .collect();
+    assert_eq!(
+        bodies,
+        vec![
+            json!({ "network_conditions": {
+                "offline": false,
+                "latency": 2000,
+                "download_throughput": 50000,
+                "upload_throughput": 50000
+            }}),
+            json!({ "network_conditions": {
+                "offline": true,
+                "latency": 0,
+                "download_throughput": 0,
+                "upload_throughput": 0
+            }}),
+            json!({ "network_conditions": {
+                "offline": false,
+                "latency": 500,
+                "download_throughput": i32::MAX,
+                "upload_throughput": -1
+            }}),
+        ]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn browser_logs() -> Result<(), WebDriverError> {
+    let mock = MockConnection::new();
+    let entry =
+        json!({ "level": "SEVERE", "message": "Uncaught Error", "timestamp": 1700000000000i64 });
+    mock.push_response(Method::POST, "se/log", json!([entry]));
+    mock.push_response(Method::GET, "se/log/types", json!(["browser", "driver", "server"]));
+    let driver = mock.connect(DesiredCapabilities::chrome()).await?;
+
+    let entries = driver.get_log(LogType::Browser).await?;
+    assert_eq!(
+        entries,
+        vec![LogEntry {
+            level: "SEVERE".to_string(),
+            message: "Uncaught Error".to_string(),
+            timestamp: 1700000000000,
+        }]
+    );
+    assert!(mock.requests().contains(&MockRequest {
+        method: Method::POST,
+        command: "se/log".to_string(),
+        body: json!({ "type": "browser" }),
+    }));
+    assert_eq!(
+        driver.get_log_types().await?,
+        vec![LogType::Browser, LogType::Driver, LogType::Other("server".to_string())]
+    );
+
+    // Drivers without the Selenium endpoint use the legacy one.
+    mock.push_error(Method::POST, "se/log", "unknown command", "");
+    mock.push_response(Method::POST, "log", json!([]));
+    assert!(driver.get_log(LogType::Performance).await?.is_empty());
+    assert!(mock.requests().contains(&MockRequest {
+        method: Method::POST,
+        command: "log".to_string(),
+        body: json!({ "type": "performance" }),
+    }));
+    driver.quit().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn wait_for_document_ready() -> Result<(), WebDriverError> {
+    let mock = MockConnection::new();
+    mock.push_response(Method::POST, "execute/sync", json!("loading"));
+    mock.push_response(Method::POST, "execute/sync", json!("interactive"));
+    mock.push_response(Method::POST, "execute/sync", json!("complete"));
+    let driver = mock.connect(DesiredCapabilities::chrome()).await?;
+    driver.wait_for_document_ready(Duration::from_secs(5)).await?;
+    let polls = mock.requests().iter().filter(|r| r.command == "execute/sync").count();
+    assert_eq!(polls, 3);
+
+    mock.push_response(Method::POST, "execute/sync", json!("interactive"));
+    assert_eq!(driver.document_ready_state().await?, "interactive");
+
+    for _ in 0..20 {
+        mock.push_response(Method::POST, "execute/sync", json!("loading"));
+    }
+    assert_matches!(
+        driver.wait_for_document_ready(Duration::from_millis(300)).await,
+        Err(WebDriverError::Timeout(_))
+    );
+    driver.quit().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn pointer_move_origins() -> Result<(), WebDriverError> {
+    let mock = MockConnection::new();
+    mock.push_response(Method::POST, "element", json!({ ELEMENT_KEY: "canvas-1" }));
+    let driver = mock.connect(DesiredCapabilities::chrome()).await?;
+    let canvas = driver.find(By::Id("canvas")).await?;
+    driver
+        .action_chain()
+        .move_to_element_with_offset(&canvas, -10, 5)
+        .move_by_offset(20, 0)
+        .perform()
+        .await?;
+    driver.quit().await?;
+
+    let requests = mock.requests();
+    let actions = requests.iter().find(|r| r.command == "actions").unwrap();
+    let mouse = &actions.body["actions"][1];
+    assert_eq!(mouse["type"], "pointer");
+    assert_eq!(
+        mouse["actions"],
+        json!([
+            { "type": "pointerMove", "origin": { ELEMENT_KEY: "canvas-1" }, "x": -10, "y": 5 },
+            { "type": "pointerMove", "origin": "pointer", "x": 20, "y": 0 }
+        ])
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn release_actions() -> Result<(), WebDriverError> {
+    let mock = MockConnection::new();
+    let driver = mock.connect(DesiredCapabilities::chrome()).await?;
+    driver.release_actions().await?;
+    driver.action_chain().reset_actions().await?;
+    driver.quit().await?;
+
+    let releases = mock
+        .requests()
+        .iter()
+        .filter(|r| r.method == Method::DELETE && r.command == "actions")
+        .count();
+    assert_eq!(releases, 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn click_and_hold_then_release() -> Result<(), WebDriverError> {
+    let mock = MockConnection::new();
+    mock.push_response(Method::POST, "element", json!({ ELEMENT_KEY: "slider-1" }));
+    let driver = mock.connect(DesiredCapabilities::chrome()).await?;
+    let slider = driver.find(By::Id("slider")).await?;
+    driver
+        .action_chain()
+        .click_and_hold_element(&slider)
+        .move_by_offset(50, 0)
+        .release()
+        .perform()
+        .await?;
+    driver.quit().await?;
+
+    let requests = mock.requests();
+    let actions = requests.iter().find(|r| r.command == "actions").unwrap();
+    assert_eq!(
+        actions.body["actions"][1]["actions"],
+        json!([
+            { "type": "pointerMove", "origin": { ELEMENT_KEY: "slider-1" }, "x": 0, "y": 0 },
+            { "type": "pointerDown", "button": 0 },
+            { "type": "pointerMove", "origin": "pointer", "x": 50, "y": 0 },
+            { "type": "pointerUp", "button": 0 }
+        ])
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn send_keys_with_modifiers() -> Result<(), WebDriverError> {
+    let mock = MockConnection::new();
+    let driver = mock.connect(DesiredCapabilities::chrome()).await?;
+    driver.send_keys(Key::Control + "a" + &Key::Null + "b").await?;
+    driver.quit().await?;
+
+    let requests = mock.requests();
+    let actions = requests.iter().find(|r| r.command == "actions").unwrap();
+    let key_actions = &actions.body["actions"][0];
+    assert_eq!(key_actions["type"], "key");
+    let ctrl = char::from(Key::Control).to_string();
+    assert_eq!(
+        key_actions["actions"],
+        json!([
+            { "type": "keyDown", "value": ctrl },
+            { "type": "keyDown", "value": "a" },
+            { "type": "keyUp", "value": "a" },
+            { "type": "keyUp", "value": ctrl },
+            { "type": "keyDown", "value": "b" },
+            { "type": "keyUp", "value": "b" }
+        ])
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn action_chain_send_keys_releases_modifiers() -> Result<(), WebDriverError> {
+    let mock = MockConnection::new();
+    let driver = mock.connect(DesiredCapabilities::chrome()).await?;
+    driver.action_chain().send_keys(Key::Control + "a").perform().await?;
+    driver.quit().await?;
+
+    // Each key, including the modifier, is pressed and released on its own.
+    let requests = mock.requests();
+    let actions = requests.iter().find(|r| r.command == "actions").unwrap();
+    let ctrl = char::from(Key::Control).to_string();
+    assert_eq!(
+        actions.body["actions"][0]["actions"],
+        json!([
+            { "type": "keyDown", "value": ctrl },
+            { "type": "keyUp", "value": ctrl },
+            { "type": "keyDown", "value": "a" },
+            { "type": "keyUp", "value": "a" }
+        ])
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn send_keys_to_element() -> Result<(), WebDriverError> {
+    let mock = MockConnection::new();
+    mock.push_response(Method::POST, "element", json!({ ELEMENT_KEY: "input-1" }));
+    let driver = mock.connect(DesiredCapabilities::chrome()).await?;
+    let elem = driver.find(By::Id("input")).await?;
+    elem.send_keys(Key::Control + "a").await?;
+    elem.send_keys(&*Key::Backspace).await?;
+    elem.send_keys("x").await?;
+    driver.quit().await?;
+
+    let sent: Vec<_> = mock
+        .requests()
+        .into_iter()
+        .filter(|r| r.command == "element/input-1/value")
+        .map(|r| r.body["text"].clone())
+        .collect();
+    assert_eq!(sent, vec![json!("\u{e009}a"), json!("\u{e003}"), json!("x")]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn permissions() -> Result<(), WebDriverError> {
+    let mock = MockConnection::new();
+    let driver = mock.connect(DesiredCapabilities::chrome()).await?;
+    mock.push_response(Method::GET, "url", json!("https://example.com/app/page"));
+    mock.push_response(Method::GET, "url", json!("https://example.com/app/page"));
+    driver.grant_permissions(&["clipboard-read", "clipboard-write"]).await?;
+    mock.push_response(Method::GET, "url", json!("about:blank"));
+    driver.set_permission("notifications", PermissionState::Prompt).await?;
+    driver.quit().await?;
+
+    let bodies: Vec<_> = mock
+        .requests()
+        .into_iter()
+        .filter(|r| r.command == "goog/cdp/execute")
+        .map(|r| r.body)
+        .collect();
+    let set_permission = |name: &str, setting: &str| {
+        json!({
+            "cmd": "Browser.setPermission",
+            "params": { "permission": { "name": name }, "setting": setting }
+        })
+    };
+    let mut clipboard_read = set_permission("clipboard-read", "granted");
+    clipboard_read["params"]["origin"] = json!("https://example.com");
+    let mut clipboard_write = set_permission("clipboard-write", "granted");
+    clipboard_write["params"]["origin"] = json!("https://example.com");
+    assert_eq!(
+        bodies,
+        vec![clipboard_read, clipboard_write, set_permission("notifications", "prompt")]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn clipboard() -> Result<(), WebDriverError> {
+    let mock = MockConnection::new();
+    let driver = mock.connect(DesiredCapabilities::chrome()).await?;
+    mock.push_response(Method::POST, "execute/async", json!({ "text": null }));
+    driver.write_clipboard("copied text").await?;
+    mock.push_response(Method::POST, "execute/async", json!({ "text": "copied text" }));
+    assert_eq!(driver.read_clipboard().await?, "copied text");
+
+    mock.push_response(
+        Method::POST,
+        "execute/async",
+        json!({ "error": "NotAllowedError: Read permission denied." }),
+    );
+    assert_matches!(
+        driver.read_clipboard().await,
+        Err(WebDriverError::CustomError(message)) if message.contains("Read permission denied")
+    );
+    driver.quit().await?;
+
+    let requests = mock.requests();
+    let write = requests.iter().find(|r| r.command == "execute/async").unwrap();
+    assert_eq!(write.body["args"], json!(["write", "copied text"]));
+    Ok(())
+}
+
+#[tokio::test]
+async fn update_window_rect() -> Result<(), WebDriverError> {
+    let mock = MockConnection::new();
+    let driver = mock.connect(DesiredCapabilities::chrome()).await?;
+    let new_rect = json!({ "x": 10, "y": 20, "width": 1024, "height": 768 });
+    mock.push_response(Method::POST, "window/rect", new_rect.clone());
+    let rect = driver.update_window_rect(OptionRect::new().with_size(1024, 768)).await?;
+    assert_eq!(rect, Rect::new(10, 20, 1024, 768));
+    mock.push_response(Method::POST, "window/rect", new_rect.clone());
+    assert_eq!(driver.update_window_rect(rect.clone()).await?, rect);
+    driver.quit().await?;
+
+    let bodies: Vec<_> = mock
+        .requests()
+        .into_iter()
+        .filter(|r| r.command == "window/rect")
+        .map(|r| r.body)
+        .collect();
+    assert_eq!(
+        bodies,
+        vec![json!({ "x": null, "y": null, "width": 1024, "height": 768 }), new_rect]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn window_size_and_position() -> Result<(), WebDriverError> {
+    let mock = MockConnection::new();
+    let driver = mock.connect(DesiredCapabilities::chrome()).await?;
+    let rect = json!({ "x": -10, "y": 20, "width": 800, "height": 600 });
+    mock.push_response(Method::POST, "window/rect", rect.clone());
+    driver.set_window_size(800, 600).await?;
+    mock.push_response(Method::POST, "window/rect", rect.clone());
+    driver.set_window_position(-10, 20).await?;
+    mock.push_response(Method::GET, "window/rect", rect.clone());
+    let (width, height) = driver.window_size().await?;
+    mock.push_response(Method::GET, "window/rect", rect);
+    let (x, y) = driver.window_position().await?;
+    assert_eq!((x, y, width, height), (-10, 20, 800, 600));
+    driver.quit().await?;
+
+    let bodies: Vec<_> = mock
+        .requests()
+        .into_iter()
+        .filter(|r| r.method == Method::POST && r.command == "window/rect")
+        .map(|r| r.body)
+        .collect();
+    assert_eq!(
+        bodies,
+        vec![
+            json!({ "x": null, "y": null, "width": 800, "height": 600 }),
+            json!({ "x": -10, "y": 20, "width": null, "height": null })
+        ]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn execute_promise_script() -> Result<(), WebDriverError> {
+    let mock = MockConnection::new();
+    let driver = mock.connect(DesiredCapabilities::chrome()).await?;
+    mock.push_response(Method::POST, "execute/async", json!({ "value": [1, 2, 3] }));
+    let value: Vec<u32> = driver
+        .execute_promise_script(
+            "return await Promise.resolve(arguments[0]);",
+            vec![json!([1, 2, 3])],
+        )
+        .await?;
+    assert_eq!(value, vec![1, 2, 3]);
+
+    mock.push_response(Method::POST, "execute/async", json!({ "error": "TypeError: failed" }));
+    let err = driver.execute_promise_script::<serde_json::Value>("return fetch('/');", Vec::new());
+    assert_matches!(err.await, Err(WebDriverError::JavascriptError(e)) if e.message.contains("TypeError"));
+    driver.quit().await?;
+
+    let requests = mock.requests();
+    let request = requests.iter().find(|r| r.command == "execute/async").unwrap();
+    assert!(request.body["script"].as_str().unwrap().contains("return await Promise.resolve"));
+    assert_eq!(request.body["args"], json!([[1, 2, 3]]));
+    Ok(())
+}
+
+#[tokio::test]
+async fn atoms_are_cached() -> Result<(), WebDriverError> {
+    let mock = MockConnection::new();
+    let driver = mock.connect(DesiredCapabilities::chrome()).await?;
+    let elements = json!({ "value": [{ ELEMENT_KEY: "elem-1" }] });
+    mock.push_response(Method::POST, "execute/sync", elements.clone());
+    mock.push_response(Method::POST, "execute/sync", elements.clone());
+    // The page has changed, so the atom must be defined again.
+    mock.push_response(Method::POST, "execute/sync", json!({ "missing": true }));
+    mock.push_response(Method::POST, "execute/sync", elements);
+    for _ in 0..3 {
+        let found = driver.find_all_relative(RelativeBy::with(By::Tag("button"))).await?;
+        assert_eq!(found.len(), 1);
+    }
+    driver.quit().await?;
+
+    let scripts: Vec<_> = mock
+        .requests()
+        .into_iter()
+        .filter(|r| r.command == "execute/sync")
+        .map(|r| r.body["script"].as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(scripts.len(), 4);
+    let defines: Vec<_> = scripts.iter().map(|s| s.contains("candidates")).collect();
+    assert_eq!(defines, vec![true, false, false, true]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn add_cookie_with_builder() -> Result<(), WebDriverError> {
+    let mock = MockConnection::new();
+    let driver = mock.connect(DesiredCapabilities::chrome()).await?;
+    let cookie = Cookie::new("session", "token")
+        .with_domain("example.com")
+        .with_path("/")
+        .with_secure(true)
+        .with_http_only(true)
+        .with_same_site(thirtyfour::cookie::SameSite::Lax);
+    driver.add_cookie(cookie).await?;
+    driver.quit().await?;
+
+    let requests = mock.requests();
+    let request = requests.iter().find(|r| r.command == "cookie").unwrap();
+    let cookie = &request.body["cookie"];
+    assert_eq!(cookie["name"], "session");
+    assert_eq!(cookie["value"], "token");
+    assert_eq!(cookie["domain"], "example.com");
+    assert_eq!(cookie["path"], "/");
+    assert_eq!(cookie["secure"], true);
+    assert_eq!(cookie["httpOnly"], true);
+    assert_eq!(cookie["sameSite"], "Lax");
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_cookie_opt() -> Result<(), WebDriverError> {
+    let mock = MockConnection::new();
+    let driver = mock.connect(DesiredCapabilities::chrome()).await?;
+    mock.push_response(
+        Method::GET,
+        "cookie/session",
+        json!({ "name": "session", "value": "token", "path": "/" }),
+    );
+    mock.push_error(Method::GET, "cookie/missing", "no such cookie", "cookie not found");
+    mock.push_error(Method::GET, "cookie/broken", "unknown error", "something went wrong");
+
+    let cookie = driver.get_cookie_opt("session").await?;
+    assert_eq!(cookie.map(|c| c.value().to_string()), Some("token".to_string()));
+    assert!(driver.get_cookie_opt("missing").await?.is_none());
+    assert_matches!(driver.get_cookie_opt("broken").await, Err(WebDriverError::UnknownError(_)));
+    driver.quit().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn save_page_source() -> Result<(), WebDriverError> {
+    let mock = MockConnection::new();
+    let driver = mock.connect(DesiredCapabilities::chrome()).await?;
+    let source = "<html><body><h1>Mock</h1></body></html>";
+    mock.push_response(Method::GET, "source", json!(source));
+    let path = std::env::temp_dir().join(format!("thirtyfour-source-{}.html", std::process::id()));
+    driver.save_page_source(&path).await?;
+    driver.quit().await?;
+
+    let saved = std::fs::read_to_string(&path)?;
+    std::fs::remove_file(&path)?;
+    assert_eq!(saved, source);
+    Ok(())
+}
+
+#[tokio::test]
+async fn element_html() -> Result<(), WebDriverError> {
+    let mock = MockConnection::new();
+    let driver = mock.connect(DesiredCapabilities::chrome()).await?;
+    mock.push_response(Method::POST, "element", json!({ ELEMENT_KEY: "list-1" }));
+    let inner = "\n  <li>One</li>\n";
+    let outer = format!("<ul>{inner}</ul>");
+    mock.push_response(Method::GET, "element/list-1/property/innerHTML", json!(inner));
+    mock.push_response(Method::GET, "element/list-1/property/outerHTML", json!(outer));
+
+    let elem = driver.find(By::Tag("ul")).await?;
+    assert_eq!(elem.inner_html().await?, inner);
+    assert_eq!(elem.outer_html().await?, outer);
+    driver.quit().await?;
+    Ok(())
+}
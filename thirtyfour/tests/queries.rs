@@ -3,7 +3,8 @@ use assert_matches::assert_matches;
 use serial_test::serial;
 use std::time::Duration;
 use thirtyfour::components::{ElementResolverMulti, ElementResolverSingle};
-use thirtyfour::{components::SelectElement, prelude::*};
+use thirtyfour::extensions::query::{wait_conditions, WebDriverWait};
+use thirtyfour::{components::SelectElement, prelude::*, RelativeBy};
 
 mod common;
 
@@ -18,6 +19,23 @@ async fn get_active_element(c: WebDriver, port: u16) -> Result<(), WebDriverErro
     c.close_window().await
 }
 
+async fn get_active_element_after_send_keys(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
+    let url = sample_page_url(port);
+    c.goto(&url).await?;
+    let elem = c.find(By::Id("text-input2")).await?;
+    elem.send_keys("thirtyfour").await?;
+
+    let active = c.active_element().await?;
+    assert_eq!(active, elem);
+
+    // Tab should move focus to the next focusable element.
+    elem.send_keys(Key::Tab.to_string()).await?;
+    let active = c.active_element().await?;
+    assert_eq!(active.id().await?, Some(String::from("button-copy")));
+
+    c.close_window().await
+}
+
 async fn find_all(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
     let url = sample_page_url(port);
     c.goto(&url).await?;
@@ -36,6 +54,32 @@ async fn query(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
     Ok(())
 }
 
+async fn query_filters(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
+    let url = sample_page_url(port);
+    c.goto(&url).await?;
+
+    let selector = By::Css("#checkbox-section input");
+    let elems = c.query(selector.clone()).and_displayed().all_from_selector().await?;
+    assert_eq!(elems.len(), 2);
+    let elems = c.query(selector.clone()).and_displayed().and_enabled().all_from_selector().await?;
+    assert_eq!(elems.len(), 1);
+    assert_eq!(elems[0].id().await?.unwrap(), "checkbox-option-1");
+
+    let elem = c.query(selector.clone()).and_not_enabled().first().await?;
+    assert_eq!(elem.id().await?.unwrap(), "checkbox-disabled");
+
+    // No element is both hidden and disabled.
+    let elem_result = c
+        .query(selector)
+        .wait(Duration::from_secs(1), Duration::from_millis(200))
+        .and_not_displayed()
+        .and_not_enabled()
+        .first()
+        .await;
+    assert_matches!(elem_result, Err(WebDriverError::NoSuchElement(_)));
+    Ok(())
+}
+
 async fn query_all(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
     let url = sample_page_url(port);
     c.goto(&url).await?;
@@ -227,6 +271,17 @@ async fn select_by_index(c: WebDriver, port: u16) -> Result<(), WebDriverError>
     Ok(())
 }
 
+async fn select_requires_select_element(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
+    let url = sample_page_url(port);
+    c.goto(&url).await?;
+
+    let elem = c.find(By::Id("text-input")).await?;
+    let result = SelectElement::new(&elem).await;
+    assert_matches!(result, Err(WebDriverError::InvalidArgument(_)));
+
+    Ok(())
+}
+
 async fn select_by_label(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
     let url = sample_page_url(port);
     c.goto(&url).await?;
@@ -252,6 +307,34 @@ async fn select_by_label(c: WebDriver, port: u16) -> Result<(), WebDriverError>
     Ok(())
 }
 
+async fn find_relative(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
+    let url = sample_page_url(port);
+    c.goto(&url).await?;
+
+    let alert_button = c.find(By::Id("button-alert")).await?;
+    let confirm_button = c.find(By::Id("button-confirm")).await?;
+
+    let elem =
+        c.find_relative(RelativeBy::with(By::Tag("button")).to_right_of(&alert_button)).await?;
+    assert_eq!(elem, confirm_button);
+    let elem =
+        c.find_relative(RelativeBy::with(By::Tag("button")).to_left_of(&confirm_button)).await?;
+    assert_eq!(elem, alert_button);
+
+    let elems = c
+        .find_all_relative(RelativeBy::with(By::Css("button[id^='button-']")).near(&alert_button))
+        .await?;
+    assert_eq!(elems[0], confirm_button);
+    assert!(!elems.contains(&alert_button));
+
+    let copy_button = c.find(By::Id("button-copy")).await?;
+    let elem_result =
+        c.find_relative(RelativeBy::with(By::Tag("button")).above(&copy_button)).await;
+    assert_matches!(elem_result, Err(WebDriverError::NoSuchElement(_)));
+
+    Ok(())
+}
+
 async fn find_element_from_element(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
     let url = sample_page_url(port);
     c.goto(&url).await?;
@@ -261,6 +344,10 @@ async fn find_element_from_element(c: WebDriver, port: u16) -> Result<(), WebDri
     let textarea = form.find(By::Tag("textarea")).await?;
     assert_eq!(textarea.attr("name").await?.unwrap(), "some_textarea");
 
+    // Elements outside of the subtree should not be found.
+    assert!(matches!(form.find(By::Id("text-input")).await, Err(WebDriverError::NoSuchElement(_))));
+    assert!(form.find_all(By::Tag("select")).await?.is_empty());
+
     // Find all.
     let nav = c.find(By::Id("navigation")).await?;
     let links = nav.find_all(By::Tag("a")).await?;
@@ -270,6 +357,92 @@ async fn find_element_from_element(c: WebDriver, port: u16) -> Result<(), WebDri
     Ok(())
 }
 
+async fn webdriver_wait(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
+    let url = sample_page_url(port);
+    c.goto(&url).await?;
+
+    let wait = WebDriverWait::new(&c, Duration::from_secs(1), Duration::from_millis(200));
+    let title = wait
+        .until(|handle| async move {
+            let title = handle.title().await?;
+            Ok((title == "Sample Page").then_some(title))
+        })
+        .await?;
+    assert_eq!(title, "Sample Page");
+
+    let result = wait.until(|_| async { Ok(None::<()>) }).await;
+    assert_matches!(result, Err(WebDriverError::Timeout(_)));
+
+    Ok(())
+}
+
+async fn webdriver_wait_conditions(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
+    let url = sample_page_url(port);
+    c.goto(&url).await?;
+
+    let wait = WebDriverWait::new(&c, Duration::from_secs(1), Duration::from_millis(200));
+    wait.until(wait_conditions::url_is(&url)).await?;
+    assert_eq!(wait.until(wait_conditions::title_contains("Sample")).await?, "Sample Page");
+
+    let elem = wait.until(wait_conditions::element_is_present(By::Id("button-copy"))).await?;
+    let elem = wait.until(wait_conditions::element_is_displayed(elem)).await?;
+    wait.until(wait_conditions::element_is_clickable(elem)).await?;
+
+    let result = wait.until(wait_conditions::element_is_present(By::Id("doesnotexist"))).await;
+    assert_matches!(result, Err(WebDriverError::Timeout(_)));
+    let result = wait.until(wait_conditions::title_contains("doesnotexist")).await;
+    assert_matches!(result, Err(WebDriverError::Timeout(_)));
+
+    // Stale elements are treated as not yet displayed.
+    let elem = c.find(By::Id("other_page_id")).await?;
+    c.execute(
+        "var elem = document.getElementById('other_page_id');
+         elem.parentNode.removeChild(elem);",
+        vec![],
+    )
+    .await?;
+    let result = wait.until(wait_conditions::element_is_displayed(elem)).await;
+    assert_matches!(result, Err(WebDriverError::Timeout(_)));
+
+    Ok(())
+}
+
+async fn find_by_constructors(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
+    let url = sample_page_url(port);
+    c.goto(&url).await?;
+
+    let elem = c.find(By::id("text-input")).await?;
+    assert_eq!(elem, c.find(By::Id("text-input")).await?);
+    assert_eq!(elem, c.find(By::css("#text-input")).await?);
+    assert_eq!(elem, c.find(By::xpath("//input[@id='text-input']")).await?);
+
+    let textarea = c.find(By::name("some_textarea")).await?;
+    assert_eq!(textarea.tag_name().await?, "textarea");
+    assert_eq!(c.find_all(By::tag("select")).await?.len(), 3);
+
+    // Partial link text, both from the document and scoped to an element.
+    let links = c.find_all(By::partial_link_text("Other")).await?;
+    assert_eq!(links.len(), 2);
+    let nav = c.find(By::id("navigation")).await?;
+    let links = nav.find_all(By::partial_link_text("Page")).await?;
+    assert_eq!(links.len(), 2);
+    Ok(())
+}
+
+async fn try_find(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
+    let url = sample_page_url(port);
+    c.goto(&url).await?;
+
+    let form = c.try_find(By::Id("textarea-form")).await?.expect("form should be found");
+    assert!(form.try_find(By::Tag("textarea")).await?.is_some());
+    assert!(form.try_find(By::Id("text-input")).await?.is_none());
+    assert!(c.try_find(By::Id("no-such-element")).await?.is_none());
+
+    // Errors other than a missing element are still returned.
+    assert_matches!(c.try_find(By::XPath("//*[")).await, Err(WebDriverError::InvalidSelector(_)));
+    Ok(())
+}
+
 mod firefox {
     use super::*;
 
@@ -279,6 +452,12 @@ mod firefox {
         local_tester!(get_active_element, "firefox");
     }
 
+    #[test]
+    #[serial]
+    fn get_active_element_after_send_keys_test() {
+        local_tester!(get_active_element_after_send_keys, "firefox");
+    }
+
     #[test]
     #[serial]
     fn find_all_test() {
@@ -344,6 +523,48 @@ mod firefox {
     fn find_element_from_element_test() {
         local_tester!(find_element_from_element, "firefox");
     }
+
+    #[test]
+    #[serial]
+    fn webdriver_wait_test() {
+        local_tester!(webdriver_wait, "firefox");
+    }
+
+    #[test]
+    #[serial]
+    fn webdriver_wait_conditions_test() {
+        local_tester!(webdriver_wait_conditions, "firefox");
+    }
+
+    #[test]
+    #[serial]
+    fn query_filters_test() {
+        local_tester!(query_filters, "firefox");
+    }
+
+    #[test]
+    #[serial]
+    fn select_requires_select_element_test() {
+        local_tester!(select_requires_select_element, "firefox");
+    }
+
+    #[test]
+    #[serial]
+    fn find_relative_test() {
+        local_tester!(find_relative, "firefox");
+    }
+
+    #[test]
+    #[serial]
+    fn find_by_constructors_test() {
+        local_tester!(find_by_constructors, "firefox");
+    }
+
+    #[test]
+    #[serial]
+    fn try_find_test() {
+        local_tester!(try_find, "firefox");
+    }
 }
 
 mod chrome {
@@ -355,6 +576,11 @@ mod chrome {
         local_tester!(get_active_element, "chrome");
     }
 
+    #[test]
+    fn get_active_element_after_send_keys_test() {
+        local_tester!(get_active_element_after_send_keys, "chrome");
+    }
+
     #[test]
     fn find_all_test() {
         local_tester!(find_all, "chrome");
@@ -404,4 +630,39 @@ mod chrome {
     fn find_element_from_element_test() {
         local_tester!(find_element_from_element, "chrome");
     }
+
+    #[test]
+    fn webdriver_wait_test() {
+        local_tester!(webdriver_wait, "chrome");
+    }
+
+    #[test]
+    fn webdriver_wait_conditions_test() {
+        local_tester!(webdriver_wait_conditions, "chrome");
+    }
+
+    #[test]
+    fn query_filters_test() {
+        local_tester!(query_filters, "chrome");
+    }
+
+    #[test]
+    fn select_requires_select_element_test() {
+        local_tester!(select_requires_select_element, "chrome");
+    }
+
+    #[test]
+    fn find_relative_test() {
+        local_tester!(find_relative, "chrome");
+    }
+
+    #[test]
+    fn find_by_constructors_test() {
+        local_tester!(find_by_constructors, "chrome");
+    }
+
+    #[test]
+    fn try_find_test() {
+        local_tester!(try_find, "chrome");
+    }
 }
@@ -2,6 +2,7 @@ use crate::common::sample_page_url;
 use common::other_page_url;
 use serial_test::serial;
 use thirtyfour::prelude::*;
+use thirtyfour::PrintParameters;
 
 mod common;
 
@@ -32,6 +33,41 @@ async fn iframe_switch(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
     c.close_window().await
 }
 
+async fn iframe_switch_to_frame(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
+    let url = sample_page_url(port);
+    c.goto(&url).await?;
+    // Go to the page that holds the iframe
+    c.find(By::Css("#iframe_page_id")).await?.click().await?;
+
+    // switch into the iframe by index
+    c.switch_to_frame(FrameSelector::Index(0)).await?;
+    c.find(By::Id("iframe_button")).await?;
+
+    // switch back to the default frame
+    c.switch_to_frame(FrameSelector::Default).await?;
+    c.find(By::Id("root_button")).await?;
+
+    // switch into the iframe again, then jump back to the main document
+    c.switch_to_frame(FrameSelector::Index(0)).await?;
+    c.find(By::Id("iframe_button")).await?;
+    c.switch_to_default_content().await?;
+    c.find(By::Id("root_button")).await?;
+
+    // switch into the iframe by element
+    let iframe_element = c.find(By::Id("iframe")).await?;
+    c.switch_to_frame(FrameSelector::Element(iframe_element)).await?;
+    c.find(By::Id("iframe_button")).await?;
+    c.find(By::Id("root_button"))
+        .await
+        .expect_err("Should not be able to access content in the root context");
+
+    // switch back up to the parent frame
+    c.switch_to_parent_frame().await?;
+    c.find(By::Id("root_button")).await?;
+
+    c.close_window().await
+}
+
 async fn new_window(c: WebDriver) -> Result<(), WebDriverError> {
     c.new_window().await?;
     let windows = c.windows().await?;
@@ -93,6 +129,44 @@ async fn new_tab_switch(c: WebDriver) -> Result<(), WebDriverError> {
     c.close_window().await
 }
 
+async fn new_window_with_type(c: WebDriver) -> Result<(), WebDriverError> {
+    let window_1 = c.window().await?;
+    let (handle, window_type) = c.new_window_with_type(WindowType::Tab).await?;
+    assert_eq!(window_type, WindowType::Tab);
+    assert_ne!(handle, window_1);
+
+    let all_windows = c.windows().await?;
+    assert_eq!(all_windows.len(), 2);
+    assert!(all_windows.contains(&handle));
+
+    c.switch_to_window(handle.clone()).await?;
+    assert_eq!(c.window().await?, handle);
+    c.close_window().await
+}
+
+async fn link_new_tab_switch(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
+    let url = sample_page_url(port);
+    c.goto(&url).await?;
+    let window_1 = c.window().await?;
+
+    // Clicking a link with target="_blank" opens a new tab.
+    c.find(By::Id("new_tab_link")).await?.click().await?;
+
+    let all_windows = c.windows().await?;
+    assert_eq!(all_windows.len(), 2);
+    let new_window = all_windows
+        .into_iter()
+        .find(|handle| handle != &window_1)
+        .expect("Should find a differing window handle");
+
+    c.switch_to_window(new_window.clone()).await?;
+    assert_eq!(c.window().await?, new_window);
+    assert_eq!(c.current_url().await?.as_ref(), other_page_url(port));
+
+    c.close_window().await?;
+    c.switch_to_window(window_1).await
+}
+
 async fn close_window(c: WebDriver) -> Result<(), WebDriverError> {
     let window_1 = c.window().await?;
     c.new_tab().await?;
@@ -183,6 +257,14 @@ async fn window_rect(c: WebDriver) -> Result<(), WebDriverError> {
     assert_eq!(r.y, 10);
     assert_eq!(r.width, 1900);
     assert_eq!(r.height, 1000);
+
+    c.set_window_size(1200, 800).await?;
+    assert_eq!(c.window_size().await?, (1200, 800));
+    assert_eq!(c.window_position().await?, (10, 10));
+
+    c.set_window_position(20, 30).await?;
+    assert_eq!(c.window_position().await?, (20, 30));
+    assert_eq!(c.window_size().await?, (1200, 800));
     Ok(())
 }
 
@@ -195,6 +277,25 @@ async fn screenshot(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
     Ok(())
 }
 
+async fn print_page(c: WebDriver, port: u16) -> Result<(), WebDriverError> {
+    let url = sample_page_url(port);
+    c.goto(&url).await?;
+
+    let pdf_data = c.print_page(PrintParameters::default()).await?;
+    assert!(pdf_data.starts_with(b"%PDF"), "print data is not a PDF document");
+    Ok(())
+}
+
+async fn window_maximize(c: WebDriver) -> Result<(), WebDriverError> {
+    c.set_window_rect(0, 0, 400, 300).await?;
+    let (before_width, before_height) = c.window_size().await?;
+    c.maximize_window().await?;
+    let (width, height) = c.window_size().await?;
+    assert!(width > before_width, "window width did not grow from {before_width}");
+    assert!(height > before_height, "window height did not grow from {before_height}");
+    Ok(())
+}
+
 mod firefox {
     use super::*;
 
@@ -204,6 +305,12 @@ mod firefox {
         local_tester!(iframe_switch, "firefox");
     }
 
+    #[test]
+    #[serial]
+    fn iframe_switch_to_frame_test() {
+        local_tester!(iframe_switch_to_frame, "firefox");
+    }
+
     #[test]
     #[serial]
     fn new_window_test() {
@@ -222,6 +329,18 @@ mod firefox {
         tester!(new_tab_switch, "firefox");
     }
 
+    #[test]
+    #[serial]
+    fn new_window_with_type_test() {
+        tester!(new_window_with_type, "firefox");
+    }
+
+    #[test]
+    #[serial]
+    fn link_new_tab_switch_test() {
+        local_tester!(link_new_tab_switch, "firefox");
+    }
+
     #[test]
     #[serial]
     fn close_window_test() {
@@ -257,6 +376,18 @@ mod firefox {
     fn screenshot_test() {
         local_tester!(screenshot, "firefox");
     }
+
+    #[test]
+    #[serial]
+    fn print_page_test() {
+        local_tester!(print_page, "firefox");
+    }
+
+    #[test]
+    #[serial]
+    fn window_maximize_test() {
+        tester!(window_maximize, "firefox");
+    }
 }
 
 mod chrome {
@@ -267,6 +398,11 @@ mod chrome {
         local_tester!(iframe_switch, "chrome");
     }
 
+    #[test]
+    fn iframe_switch_to_frame_test() {
+        local_tester!(iframe_switch_to_frame, "chrome");
+    }
+
     #[test]
     fn new_window_test() {
         tester!(new_window, "chrome");
@@ -282,6 +418,16 @@ mod chrome {
         tester!(new_tab_switch, "chrome");
     }
 
+    #[test]
+    fn new_window_with_type_test() {
+        tester!(new_window_with_type, "chrome");
+    }
+
+    #[test]
+    fn link_new_tab_switch_test() {
+        local_tester!(link_new_tab_switch, "chrome");
+    }
+
     #[test]
     fn close_window_test() {
         tester!(close_window, "chrome");
@@ -312,4 +458,14 @@ mod chrome {
     fn screenshot_test() {
         local_tester!(screenshot, "chrome");
     }
+
+    #[test]
+    fn print_page_test() {
+        local_tester!(print_page, "chrome");
+    }
+
+    #[test]
+    fn window_maximize_test() {
+        tester!(window_maximize, "chrome");
+    }
 }